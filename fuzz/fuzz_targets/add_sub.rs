@@ -0,0 +1,20 @@
+//! `a + b - b == a` and `a * k` agreeing with repeated addition.
+#![no_main]
+
+use byte_arithmetic::Base256;
+use libfuzzer_sys::fuzz_target;
+
+fn significant(value: &Base256) -> &[u8] {
+    &value[value.iter().take_while(|x| **x == 0).count()..]
+}
+
+fuzz_target!(|input: (Base256, Base256, u8)| {
+    let (a, b, k) = input;
+    let round_trip = &(&a + &b) - &b;
+    assert_eq!(significant(&round_trip), significant(&a));
+    let mut repeated = Base256::new(vec![0]);
+    for _ in 0..k {
+        repeated = repeated + &a;
+    }
+    assert_eq!(significant(&(&a * k)), significant(&repeated));
+});
@@ -0,0 +1,22 @@
+//! `wrapped_add` is associative and commutative at a fixed width, and never exceeds it.
+#![no_main]
+
+use byte_arithmetic::Base256;
+use libfuzzer_sys::fuzz_target;
+
+fn significant(value: &Base256) -> &[u8] {
+    &value[value.iter().take_while(|x| **x == 0).count()..]
+}
+
+fuzz_target!(|input: (Base256, Base256, Base256, u8)| {
+    let (a, b, c, width) = input;
+    let width = width as usize % 48;
+    let left = a.clone().wrapped_add(b.clone(), width).wrapped_add(c.clone(), width);
+    let right = a.clone().wrapped_add(b.clone().wrapped_add(c, width), width);
+    assert!(left.len() <= width && right.len() <= width);
+    assert_eq!(significant(&left), significant(&right));
+    assert_eq!(
+        significant(&a.clone().wrapped_add(b.clone(), width)),
+        significant(&b.wrapped_add(a, width))
+    );
+});
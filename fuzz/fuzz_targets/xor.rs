@@ -0,0 +1,13 @@
+//! `(a ^ b) ^ b == a` and `a ^ b == b ^ a` for operands of equal width.
+#![no_main]
+
+use byte_arithmetic::Base256;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (Base256, Vec<u8>)| {
+    let (a, mut key) = input;
+    key.resize(a.len(), 0);
+    let b = Base256::new(key);
+    assert_eq!((&a ^ &b) ^ &b, a);
+    assert_eq!(&a ^ &b, &b ^ &a);
+});
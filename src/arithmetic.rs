@@ -0,0 +1,112 @@
+use crate::Base256;
+
+/// Arithmetic shared by byte-like values, so downstream code can be generic over ID width.
+///
+/// Implemented by Base256, [`FixedBase256`](crate::FixedBase256) and, for small widths or tests,
+/// by `u64` and `u128`. Fixed width types panic on overflow in `add` and `scalar_multiply` where
+/// Base256 would grow instead, and wrap at the smaller of `byte_length` and their own width in
+/// `wrapped_add`.
+/// ```
+/// use byte_arithmetic::{Base256, ByteArithmetic};
+/// fn distance<T: ByteArithmetic>(a: &T, b: &T) -> T {
+///     a.xor(b)
+/// }
+/// assert_eq!(distance(&0b1010u64, &0b0110u64), 0b1100);
+/// assert_eq!(
+///     distance(&Base256::new(vec![0b1010]), &Base256::new(vec![0b0110])),
+///     Base256::new(vec![0b1100])
+/// );
+/// ```
+pub trait ByteArithmetic: Sized {
+    fn add(&self, rhs: &Self) -> Self;
+
+    fn wrapped_add(&self, rhs: &Self, byte_length: usize) -> Self;
+
+    /// Panics if `rhs` is larger than `self`.
+    fn sub(&self, rhs: &Self) -> Self;
+
+    fn xor(&self, rhs: &Self) -> Self;
+
+    fn scalar_multiply(&self, value: u8) -> Self;
+}
+
+impl ByteArithmetic for Base256 {
+    fn add(&self, rhs: &Self) -> Self {
+        self + rhs
+    }
+
+    fn wrapped_add(&self, rhs: &Self, byte_length: usize) -> Self {
+        (self + rhs).wrap_to(byte_length)
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        self - rhs
+    }
+
+    fn xor(&self, rhs: &Self) -> Self {
+        self ^ rhs
+    }
+
+    fn scalar_multiply(&self, value: u8) -> Self {
+        self * value
+    }
+}
+
+macro_rules! impl_byte_arithmetic {
+    ($($t:ty),*) => {
+        $(
+            impl ByteArithmetic for $t {
+                fn add(&self, rhs: &Self) -> Self {
+                    self.checked_add(*rhs).expect("Overflow")
+                }
+
+                fn wrapped_add(&self, rhs: &Self, byte_length: usize) -> Self {
+                    let res = self.wrapping_add(*rhs);
+                    if byte_length >= core::mem::size_of::<$t>() {
+                        return res;
+                    }
+                    res & ((1 << (8 * byte_length)) - 1)
+                }
+
+                fn sub(&self, rhs: &Self) -> Self {
+                    self.checked_sub(*rhs).expect("Underflow")
+                }
+
+                fn xor(&self, rhs: &Self) -> Self {
+                    self ^ rhs
+                }
+
+                fn scalar_multiply(&self, value: u8) -> Self {
+                    self.checked_mul(value as $t).expect("Overflow")
+                }
+            }
+        )*
+    };
+}
+
+impl_byte_arithmetic!(u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sum_then_wrap<T: ByteArithmetic>(a: T, b: T) -> T {
+        ByteArithmetic::wrapped_add(&a.scalar_multiply(2), &b, 2)
+    }
+
+    #[test]
+    fn test_primitive_matches_base256() {
+        assert_eq!(sum_then_wrap(0xFFFFu64, 3), 1);
+        assert_eq!(
+            sum_then_wrap(Base256::new(vec![255, 255]), Base256::new(vec![3])),
+            Base256::new(vec![0, 1])
+        );
+        assert_eq!(ByteArithmetic::wrapped_add(&u128::MAX, &1, 32), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Underflow")]
+    fn test_primitive_sub_underflow() {
+        ByteArithmetic::sub(&1u64, &2);
+    }
+}
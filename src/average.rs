@@ -0,0 +1,119 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::{zip_padded, Base256};
+use core::borrow::Borrow;
+
+impl Base256 {
+    /// `floor((self + other) / 2)` at the width of the wider operand.
+    ///
+    /// The carry out of the sum is shifted straight back in, so the intermediate never needs an
+    /// extra byte.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let low = Base256::new(vec![0xff, 0xfe]);
+    /// let high = Base256::new(vec![0xff, 0xff]);
+    /// assert_eq!(low.midpoint(&high), Base256::new(vec![0xff, 0xfe]));
+    /// let short = Base256::new(vec![2]);
+    /// assert_eq!(short.midpoint(&Base256::new(vec![1, 0])), Base256::new(vec![0, 0x81]));
+    /// ```
+    pub fn midpoint(&self, other: &Self) -> Self {
+        let mut res = Vec::with_capacity(self.len().max(other.len()));
+        let mut carry = 0u16;
+        for (x, y) in zip_padded(self.iter().rev(), other.iter().rev()) {
+            let acc = x as u16 + y as u16 + carry;
+            res.push(acc as u8);
+            carry = acc >> 8;
+        }
+        res.reverse();
+        let mut high = carry as u8;
+        for byte in res.iter_mut() {
+            let low = *byte & 1;
+            *byte = (*byte >> 1) | (high << 7);
+            high = low;
+        }
+        Base256::new(res)
+    }
+
+    /// Floor of the mean of `values`, each wrapped to `byte_length` bytes, at that width. `None`
+    /// if there are no values.
+    ///
+    /// The running sum is held in `byte_length + 8` bytes, which no count of values reachable
+    /// through a `u64` can overflow, so the sum is never wrapped or reallocated.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let keys = [0xff, 0xfe, 0x01].map(|x| Base256::new(vec![x]));
+    /// assert_eq!(Base256::mean(&keys, 1), Some(Base256::new(vec![0xaa])));
+    /// assert_eq!(Base256::mean(Vec::<Base256>::new(), 1), None);
+    /// ```
+    pub fn mean<T, I>(values: I, byte_length: usize) -> Option<Self>
+    where
+        T: Borrow<Base256>,
+        I: IntoIterator<Item = T>,
+    {
+        let mut sum = vec![0u8; byte_length + 8];
+        let mut count = 0u64;
+        for value in values {
+            let value = value.borrow();
+            let mut bytes = value.iter().rev().take(byte_length);
+            let mut carry = 0u16;
+            for acc in sum.iter_mut().rev() {
+                let next = bytes.next();
+                if next.is_none() && carry == 0 {
+                    break;
+                }
+                let total = *acc as u16 + next.copied().unwrap_or(0) as u16 + carry;
+                *acc = total as u8;
+                carry = total >> 8;
+            }
+            count += 1;
+        }
+        if count == 0 {
+            return None;
+        }
+        let (quotient, _) = Base256::new(sum).div_rem_u64(count);
+        Some(Base256::new(quotient.wrapped_bytes(byte_length)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_midpoint_matches_u64() {
+        let values = [0u64, 1, 2, 0xff, 0x1_0000, u64::MAX - 1, u64::MAX];
+        for a in values {
+            for b in values {
+                let midpoint = Base256::from(a).midpoint(&Base256::from(b));
+                let expected = ((a as u128 + b as u128) / 2) as u64;
+                assert_eq!(u64::try_from(&midpoint), Ok(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn test_mean_matches_u128() {
+        let values = [u64::MAX, u64::MAX - 5, 1, 0x8000_0000_0000_0000, 12345];
+        let mean = Base256::mean(values.iter().map(|x| Base256::from(*x)), 8).unwrap();
+        let expected = values.iter().map(|x| *x as u128).sum::<u128>() / values.len() as u128;
+        assert_eq!(u64::try_from(&mean), Ok(expected as u64));
+        assert_eq!(mean.len(), 8);
+    }
+
+    #[test]
+    fn test_mean_wraps_inputs() {
+        let values = vec![Base256::new(vec![1, 0, 4]), Base256::new(vec![2])];
+        assert_eq!(Base256::mean(values, 2), Some(Base256::new(vec![0, 3])));
+        let many = core::iter::repeat(Base256::new(vec![0xff])).take(100_000);
+        assert_eq!(Base256::mean(many, 1), Some(Base256::new(vec![0xff])));
+    }
+
+    #[test]
+    fn test_midpoint_keeps_width() {
+        let max = Base256::new(vec![0xff; 4]);
+        assert_eq!(max.midpoint(&max), max);
+        assert_eq!(Base256::new(vec![0, 0, 3]).midpoint(&Base256::new(vec![1])).len(), 3);
+        assert_eq!(Base256::empty().midpoint(&Base256::empty()), Base256::empty());
+    }
+}
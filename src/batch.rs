@@ -0,0 +1,191 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::Base256;
+use core::borrow::Borrow;
+
+/// Many values of the same `width`, stored a column at a time: column `j` holds byte `j` of every
+/// value, contiguously.
+///
+/// Reductions and element-wise operations then walk each column in order, from the least
+/// significant, carrying between columns in a register rather than allocating per value. Values
+/// are wrapped to `width` bytes as they are pushed.
+/// ```
+/// use byte_arithmetic::{Base256, Batch256};
+/// let batch = Batch256::from_values([vec![0xff, 0xff], vec![0, 2]].map(Base256::new), 2);
+/// assert_eq!(batch.sum(3), Base256::new(vec![1, 0, 1]));
+/// assert_eq!(batch.xor_fold(), Base256::new(vec![0xff, 0xfd]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Batch256 {
+    width: usize,
+    len: usize,
+    columns: Vec<Vec<u8>>,
+}
+
+impl Batch256 {
+    pub fn new(width: usize) -> Self {
+        Batch256 {
+            width,
+            len: 0,
+            columns: vec![Vec::new(); width],
+        }
+    }
+
+    pub fn from_values<T, I>(values: I, width: usize) -> Self
+    where
+        T: Borrow<Base256>,
+        I: IntoIterator<Item = T>,
+    {
+        let mut batch = Batch256::new(width);
+        values.into_iter().for_each(|x| batch.push(x.borrow()));
+        batch
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push(&mut self, value: &Base256) {
+        for (column, x) in self.columns.iter_mut().zip(value.wrapped_bytes(self.width)) {
+            column.push(x);
+        }
+        self.len += 1;
+    }
+
+    /// The value at `index`, `width` bytes long.
+    pub fn get(&self, index: usize) -> Option<Base256> {
+        if index >= self.len {
+            return None;
+        }
+        Some(Base256::new(self.columns.iter().map(|x| x[index]).collect()))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Base256> + '_ {
+        (0..self.len).map(|x| self.get(x).unwrap())
+    }
+
+    /// Sum of every value modulo `2^(8 * byte_length)`, exactly `byte_length` bytes long.
+    ///
+    /// Each column is summed on its own and the running carry moves up one byte per column.
+    pub fn sum(&self, byte_length: usize) -> Base256 {
+        let mut res = Vec::with_capacity(byte_length);
+        let mut carry = 0u128;
+        let mut columns = self.columns.iter().rev();
+        while res.len() < byte_length {
+            if let Some(column) = columns.next() {
+                carry += column.iter().map(|x| *x as u128).sum::<u128>();
+            }
+            res.push(carry as u8);
+            carry >>= 8;
+        }
+        res.reverse();
+        Base256::new(res)
+    }
+
+    /// Xor of every value, `width` bytes long.
+    pub fn xor_fold(&self) -> Base256 {
+        Base256::new(
+            self.columns
+                .iter()
+                .map(|column| column.iter().fold(0, |acc, x| acc ^ x))
+                .collect(),
+        )
+    }
+}
+
+/// Element-wise sum, each wrapping at the batch width.
+///
+/// Panics if the batches differ in width or length.
+/// ```
+/// use byte_arithmetic::{Base256, Batch256};
+/// let a = Batch256::from_values([vec![0, 0xff], vec![0xff, 0xff]].map(Base256::new), 2);
+/// let b = Batch256::from_values([vec![0, 1], vec![0, 1]].map(Base256::new), 2);
+/// let sums: Vec<Base256> = (&a + &b).iter().collect();
+/// assert_eq!(sums, [vec![1, 0], vec![0, 0]].map(Base256::new));
+/// ```
+impl core::ops::Add<&Batch256> for &Batch256 {
+    type Output = Batch256;
+
+    fn add(self, rhs: &Batch256) -> Batch256 {
+        if self.width != rhs.width || self.len != rhs.len {
+            panic!("Batches must have the same width and length")
+        }
+        let mut columns = vec![Vec::new(); self.width];
+        let mut carries = vec![0u8; self.len];
+        for (j, (x, y)) in self.columns.iter().zip(&rhs.columns).enumerate().rev() {
+            columns[j] = x
+                .iter()
+                .zip(y)
+                .zip(carries.iter_mut())
+                .map(|((x, y), carry)| {
+                    let total = *x as u16 + *y as u16 + *carry as u16;
+                    *carry = (total >> 8) as u8;
+                    total as u8
+                })
+                .collect();
+        }
+        Batch256 {
+            width: self.width,
+            len: self.len,
+            columns,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WrappedSum;
+
+    fn values() -> Vec<Base256> {
+        (0..500u64)
+            .map(|x| Base256::new(x.wrapping_mul(0x9e37_79b9_7f4a_7c15).to_be_bytes().to_vec()))
+            .collect()
+    }
+
+    #[test]
+    fn test_sum_matches_wrapped_sum() {
+        let batch = Batch256::from_values(values(), 8);
+        assert_eq!(batch.len(), 500);
+        for byte_length in [0, 1, 4, 8, 12] {
+            let expected = values().into_iter().wrapped_sum(byte_length).with_width(byte_length);
+            assert_eq!(batch.sum(byte_length), expected);
+        }
+        assert_eq!(Batch256::new(2).sum(3), Base256::new(vec![0, 0, 0]));
+    }
+
+    #[test]
+    fn test_xor_fold_and_round_trip() {
+        let batch = Batch256::from_values(values(), 8);
+        let expected = values().iter().fold(Base256::new(vec![0; 8]), |acc, x| &acc ^ x);
+        assert_eq!(batch.xor_fold(), expected);
+        assert_eq!(batch.iter().collect::<Vec<_>>(), values());
+        assert_eq!(batch.get(500), None);
+        // Pushed values are wrapped to the batch width.
+        let narrow = Batch256::from_values(values(), 2);
+        assert_eq!(narrow.get(3), Some(values()[3].clone().wrap_to(2)));
+    }
+
+    #[test]
+    fn test_add_matches_wrapped_add() {
+        let (a, b) = (values(), values().into_iter().rev().collect::<Vec<_>>());
+        let sum = &Batch256::from_values(&a, 8) + &Batch256::from_values(&b, 8);
+        for (i, (x, y)) in a.into_iter().zip(b).enumerate() {
+            assert_eq!(sum.get(i), Some(x.wrapped_add(y, 8).with_width(8)));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Batches must have the same width and length")]
+    fn test_add_mismatched_batches() {
+        let _ = &Batch256::from_values(values(), 8) + &Batch256::new(8);
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{cmp_numeric, ArithmeticError, Base256};
+use core::cmp::Ordering;
+
+/// Apply `op` to the bytes of `a` and `b` from the least significant end, as many as the widest
+/// of `a`, `b` and `out`, writing the low `out.len()` result bytes into `out`.
+///
+/// `op` takes the two bytes and the carried state and returns the result byte and the state to
+/// carry on. Returns the final state, or `Overflow` if a result byte beyond `out` is non-zero.
+fn apply_into(
+    a: &[u8],
+    b: &[u8],
+    out: &mut [u8],
+    op: impl Fn(u8, u8, u8) -> (u8, u8),
+) -> Result<u8, ArithmeticError> {
+    let width = a.len().max(b.len()).max(out.len());
+    let byte_from_end = |bytes: &[u8], i: usize| match bytes.len().checked_sub(i + 1) {
+        Some(index) => bytes[index],
+        None => 0,
+    };
+    let mut state = 0;
+    let mut truncated = false;
+    for i in 0..width {
+        let (byte, next) = op(byte_from_end(a, i), byte_from_end(b, i), state);
+        state = next;
+        match out.len().checked_sub(i + 1) {
+            Some(index) => out[index] = byte,
+            None => truncated |= byte != 0,
+        }
+    }
+    if truncated {
+        return Err(ArithmeticError::Overflow);
+    }
+    Ok(state)
+}
+
+impl Base256 {
+    /// Write `self + rhs` into `out` as exactly `out.len()` big-endian bytes, without allocating.
+    ///
+    /// Operands of any width are read by numeric value. Fails with
+    /// [`ArithmeticError::Overflow`] if the sum does not fit; `out` is then left with the low
+    /// bytes of the sum.
+    /// ```
+    /// use byte_arithmetic::{ArithmeticError, Base256};
+    /// let mut out = [0u8; 2];
+    /// Base256::new(vec![0x01, 0xff]).add_into(&Base256::new(vec![1]), &mut out).unwrap();
+    /// assert_eq!(out, [0x02, 0x00]);
+    /// let overflow = Base256::new(vec![0xff, 0xff]).add_into(&Base256::new(vec![1]), &mut out);
+    /// assert_eq!(overflow, Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn add_into(&self, rhs: &Base256, out: &mut [u8]) -> Result<(), ArithmeticError> {
+        let carry = apply_into(self, rhs, out, |x, y, carry| {
+            let sum = x as u16 + y as u16 + carry as u16;
+            (sum as u8, (sum >> 8) as u8)
+        })?;
+        if carry > 0 {
+            return Err(ArithmeticError::Overflow);
+        }
+        Ok(())
+    }
+
+    /// Write `self - rhs` into `out` as exactly `out.len()` big-endian bytes, without allocating.
+    ///
+    /// Fails with [`ArithmeticError::Underflow`] if `rhs` is greater than `self`, or
+    /// [`ArithmeticError::Overflow`] if the difference does not fit; `out` is then unspecified.
+    pub fn sub_into(&self, rhs: &Base256, out: &mut [u8]) -> Result<(), ArithmeticError> {
+        if cmp_numeric(self, rhs) == Ordering::Less {
+            return Err(ArithmeticError::Underflow);
+        }
+        apply_into(self, rhs, out, |x, y, borrow| {
+            let diff = (x as u16).wrapping_sub(y as u16).wrapping_sub(borrow as u16);
+            // A negative difference wraps to 0xff00 or above.
+            (diff as u8, (diff >> 15) as u8)
+        })
+        .map(|_| ())
+    }
+
+    /// Write `self ^ rhs` into `out` as exactly `out.len()` big-endian bytes, without allocating.
+    ///
+    /// Unlike `^`, operands are aligned at their least significant byte, as numbers. Fails with
+    /// [`ArithmeticError::Overflow`] if the result has non-zero bytes beyond `out`.
+    pub fn xor_into(&self, rhs: &Base256, out: &mut [u8]) -> Result<(), ArithmeticError> {
+        apply_into(self, rhs, out, |x, y, _| (x ^ y, 0)).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_into() {
+        let mut out = [0xaa; 4];
+        let wide = Base256::new(vec![0, 0, 0, 0, 0, 1]);
+        wide.add_into(&Base256::new(vec![0xff]), &mut out).unwrap();
+        assert_eq!(out, [0, 0, 1, 0]);
+        let mut narrow = [0u8; 1];
+        assert_eq!(wide.add_into(&wide, &mut narrow), Ok(()));
+        assert_eq!(narrow, [2]);
+        assert_eq!(
+            Base256::new(vec![1, 0]).add_into(&Base256::new(vec![1]), &mut narrow),
+            Err(ArithmeticError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_sub_into() {
+        let mut out = [0u8; 1];
+        Base256::new(vec![1, 0]).sub_into(&Base256::new(vec![0xff]), &mut out).unwrap();
+        assert_eq!(out, [1]);
+        assert_eq!(
+            Base256::new(vec![1]).sub_into(&Base256::new(vec![1, 0]), &mut out),
+            Err(ArithmeticError::Underflow)
+        );
+        assert_eq!(
+            Base256::new(vec![1]).sub_into(&Base256::new(vec![2]), &mut out),
+            Err(ArithmeticError::Underflow)
+        );
+        assert_eq!(
+            Base256::new(vec![2, 0]).sub_into(&Base256::new(vec![1]), &mut out),
+            Err(ArithmeticError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_xor_into() {
+        let mut out = [0u8; 3];
+        Base256::new(vec![0xf0]).xor_into(&Base256::new(vec![1, 0x0f]), &mut out).unwrap();
+        assert_eq!(out, [0, 1, 0xff]);
+        let mut narrow = [0u8; 1];
+        assert_eq!(
+            Base256::new(vec![1, 0]).xor_into(&Base256::new(vec![0]), &mut narrow),
+            Err(ArithmeticError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use alloc::vec::Vec;
+use crate::{Base256, ParseError};
+
+/// CRC-32 (IEEE 802.3, reflected polynomial `0xedb88320`) lookup table.
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, byte| {
+        CRC32_TABLE[((crc ^ *byte as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+impl Base256 {
+    /// The bytes of the value, including leading zeros, followed by their big-endian CRC-32.
+    /// ```
+    /// use byte_arithmetic::{Base256, ParseError};
+    /// let checked = Base256::new(vec![0, 1, 2]).to_checked_bytes();
+    /// assert_eq!(checked.len(), 7);
+    /// assert_eq!(Base256::from_checked_bytes(&checked), Ok(Base256::new(vec![0, 1, 2])));
+    /// let mut corrupted = checked.clone();
+    /// corrupted[1] ^= 0x10;
+    /// assert_eq!(Base256::from_checked_bytes(&corrupted), Err(ParseError::InvalidChecksum));
+    /// ```
+    pub fn to_checked_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(self.len() + 4);
+        res.extend_from_slice(self);
+        res.extend_from_slice(&crc32(self).to_be_bytes());
+        res
+    }
+
+    /// Verify and strip the checksum appended by
+    /// [`to_checked_bytes`](Base256::to_checked_bytes).
+    ///
+    /// Fails with [`ParseError::InvalidLength`] if there are fewer than four bytes, and with
+    /// [`ParseError::InvalidChecksum`] if the checksum does not match.
+    pub fn from_checked_bytes(bytes: &[u8]) -> Result<Self, ParseError> {
+        if bytes.len() < 4 {
+            return Err(ParseError::InvalidLength);
+        }
+        let (data, checksum) = bytes.split_at(bytes.len() - 4);
+        if crc32(data).to_be_bytes() != checksum {
+            return Err(ParseError::InvalidChecksum);
+        }
+        Ok(Base256::from(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn test_checked_bytes() {
+        assert_eq!(Base256::empty().to_checked_bytes(), vec![0, 0, 0, 0]);
+        assert_eq!(Base256::from_checked_bytes(&[0, 0, 0, 0]), Ok(Base256::empty()));
+        assert_eq!(Base256::from_checked_bytes(&[0, 0, 0]), Err(ParseError::InvalidLength));
+        let checked = Base256::new(b"123456789".to_vec()).to_checked_bytes();
+        assert_eq!(checked[9..], [0xcb, 0xf4, 0x39, 0x26]);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{cmp_numeric, Base256};
+use core::cmp::Ordering;
+
+/// Comparisons against primitive integers use numeric semantics, so leading zero bytes are
+/// ignored, unlike the derived `Ord` between two Base256 values.
+/// ```
+/// use byte_arithmetic::Base256;
+/// assert!(Base256::new(vec![0, 0]) == 0u64);
+/// assert!(Base256::new(vec![0, 1, 0]) > 255u64);
+/// assert!(Base256::new(vec![15, 66, 63]) < 1_000_000u128);
+/// ```
+macro_rules! impl_primitive_cmp {
+    ($($t:ty),*) => {
+        $(
+            impl PartialEq<$t> for Base256 {
+                fn eq(&self, other: &$t) -> bool {
+                    cmp_numeric(&self.inner, &other.to_be_bytes()) == Ordering::Equal
+                }
+            }
+
+            impl PartialEq<Base256> for $t {
+                fn eq(&self, other: &Base256) -> bool {
+                    other == self
+                }
+            }
+
+            impl PartialOrd<$t> for Base256 {
+                fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                    Some(cmp_numeric(&self.inner, &other.to_be_bytes()))
+                }
+            }
+
+            impl PartialOrd<Base256> for $t {
+                fn partial_cmp(&self, other: &Base256) -> Option<Ordering> {
+                    other.partial_cmp(self).map(Ordering::reverse)
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive_cmp!(u64, u128);
+
+/// Comparisons against byte slices are exact, including leading zeros, so a Base256 can be
+/// checked against raw digest output.
+/// ```
+/// use byte_arithmetic::Base256;
+/// let digest: [u8; 2] = [0, 7];
+/// assert_eq!(Base256::new(vec![0, 7]), digest.as_slice());
+/// assert_ne!(Base256::new(vec![7]), digest.as_slice());
+/// ```
+impl PartialEq<[u8]> for Base256 {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.inner.as_slice() == other
+    }
+}
+
+impl PartialEq<&[u8]> for Base256 {
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.inner.as_slice() == *other
+    }
+}
+
+impl PartialEq<Base256> for [u8] {
+    fn eq(&self, other: &Base256) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<Base256> for &[u8] {
+    fn eq(&self, other: &Base256) -> bool {
+        other == self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eq_primitive() {
+        assert_eq!(Base256::empty(), 0u64);
+        assert_eq!(Base256::new(vec![0, 1, 2]), 258u128);
+        assert_ne!(Base256::new(vec![1, 0, 0, 0, 0, 0, 0, 0, 0]), 0u64);
+    }
+
+    #[test]
+    fn test_ord_primitive() {
+        assert!(Base256::new(vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 1]) < 2u64);
+        assert!(Base256::new(vec![1, 0, 0, 0, 0, 0, 0, 0, 0]) > u64::MAX);
+        assert!(5u64 > Base256::new(vec![4]));
+        assert!(u128::MAX >= Base256::new(vec![255; 16]));
+    }
+
+    #[test]
+    fn test_eq_slice() {
+        let bytes: &[u8] = &[1, 2, 3];
+        assert_eq!(Base256::new(vec![1, 2, 3]), *bytes);
+        assert_eq!(bytes, Base256::new(vec![1, 2, 3]));
+        assert_ne!(Base256::new(vec![0, 1, 2, 3]), bytes);
+    }
+}
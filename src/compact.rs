@@ -0,0 +1,113 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::{Base256, ParseError};
+
+/// Version byte leading every compact encoding.
+const VERSION: u8 = 1;
+
+impl Base256 {
+    /// Canonical storage encoding: a version byte, the number of significant bytes as an
+    /// unsigned LEB128 varint, then the significant bytes.
+    ///
+    /// Version 1 of this layout will not change between crate versions; any other layout will use
+    /// a new version byte. Leading zero bytes are not stored, so equal numbers encode identically
+    /// whatever their width, and zero is `[1, 0]`.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let value = Base256::new(vec![0, 0, 0x12, 0x34]);
+    /// assert_eq!(value.to_compact_bytes(), vec![1, 2, 0x12, 0x34]);
+    /// assert_eq!(
+    ///     Base256::from_compact_bytes(&[1, 2, 0x12, 0x34]),
+    ///     Ok(Base256::new(vec![0x12, 0x34]))
+    /// );
+    /// ```
+    pub fn to_compact_bytes(&self) -> Vec<u8> {
+        let payload = self.significant_bytes();
+        let mut res = Vec::with_capacity(payload.len() + 11);
+        res.push(VERSION);
+        let mut len = payload.len();
+        loop {
+            let byte = (len & 0x7f) as u8;
+            len >>= 7;
+            if len == 0 {
+                res.push(byte);
+                break;
+            }
+            res.push(byte | 0x80);
+        }
+        res.extend_from_slice(payload);
+        res
+    }
+
+    /// Decode [`to_compact_bytes`](Base256::to_compact_bytes) output, returning the value with
+    /// its significant bytes only, or `[0]` for zero.
+    ///
+    /// Only the canonical encoding is accepted: an unknown version, an over-long varint or a
+    /// leading zero byte fail with [`ParseError::InvalidDigit`] at the offending index, and
+    /// truncated or trailing data with [`ParseError::InvalidLength`].
+    pub fn from_compact_bytes(bytes: &[u8]) -> Result<Self, ParseError> {
+        match bytes.first() {
+            None => return Err(ParseError::Empty),
+            Some(&VERSION) => {}
+            Some(_) => return Err(ParseError::InvalidDigit { index: 0 }),
+        }
+        // The length is read into a u64 from at most 9 varint bytes; anything longer could never
+        // match the data that follows anyway.
+        let mut len: u64 = 0;
+        let mut index = 1;
+        loop {
+            let byte = *bytes.get(index).ok_or(ParseError::InvalidLength)?;
+            if index > 9 || (index > 1 && byte == 0) {
+                return Err(ParseError::InvalidDigit { index });
+            }
+            len |= ((byte & 0x7f) as u64) << (7 * (index - 1));
+            index += 1;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        let payload = &bytes[index..];
+        if payload.len() as u64 != len {
+            return Err(ParseError::InvalidLength);
+        }
+        match payload.first() {
+            None => Ok(Base256::new(vec![0])),
+            Some(0) => Err(ParseError::InvalidDigit { index }),
+            Some(_) => Ok(Base256::from(payload)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compact_round_trip() {
+        assert_eq!(Base256::new(vec![0, 0]).to_compact_bytes(), vec![1, 0]);
+        assert_eq!(Base256::from_compact_bytes(&[1, 0]), Ok(Base256::new(vec![0])));
+        let long = Base256::new(vec![0xaa; 200]);
+        let encoded = long.to_compact_bytes();
+        assert_eq!(encoded[..3], [1, 0xc8, 0x01]);
+        assert_eq!(Base256::from_compact_bytes(&encoded), Ok(long));
+    }
+
+    #[test]
+    fn test_compact_rejects_non_canonical() {
+        assert_eq!(Base256::from_compact_bytes(&[]), Err(ParseError::Empty));
+        assert_eq!(
+            Base256::from_compact_bytes(&[2, 0]),
+            Err(ParseError::InvalidDigit { index: 0 })
+        );
+        assert_eq!(
+            Base256::from_compact_bytes(&[1, 0x81, 0x00, 5]),
+            Err(ParseError::InvalidDigit { index: 2 })
+        );
+        assert_eq!(
+            Base256::from_compact_bytes(&[1, 2, 0, 5]),
+            Err(ParseError::InvalidDigit { index: 2 })
+        );
+        assert_eq!(Base256::from_compact_bytes(&[1, 2, 5]), Err(ParseError::InvalidLength));
+        assert_eq!(Base256::from_compact_bytes(&[1, 1, 5, 6]), Err(ParseError::InvalidLength));
+    }
+}
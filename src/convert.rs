@@ -0,0 +1,328 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::{Base256, OverflowError};
+
+/// Unsigned primitives convert to their minimal big-endian representation, with zero as `[0]`.
+/// ```
+/// use byte_arithmetic::Base256;
+/// assert_eq!(Base256::from(0u8), Base256::new(vec![0]));
+/// assert_eq!(Base256::from(258u32), Base256::new(vec![1, 2]));
+/// assert_eq!(Base256::from(u64::MAX), Base256::new(vec![255; 8]));
+/// ```
+macro_rules! impl_from_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Base256 {
+                fn from(value: $t) -> Self {
+                    Base256::new(value.to_be_bytes().to_vec()).with_width(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_primitive!(u8, u16, u32, u64, u128, usize);
+
+/// Leading zeros are ignored, so only the numeric value has to fit.
+/// ```
+/// use byte_arithmetic::Base256;
+/// assert_eq!(u64::try_from(&Base256::new(vec![0, 0, 1, 2])), Ok(258));
+/// assert!(u64::try_from(&Base256::new(vec![1; 9])).is_err());
+/// ```
+macro_rules! impl_try_from_base256 {
+    ($($t:ty),*) => {
+        $(
+            impl TryFrom<&Base256> for $t {
+                type Error = OverflowError;
+
+                fn try_from(value: &Base256) -> Result<Self, Self::Error> {
+                    let bytes = value.significant_bytes();
+                    let size = core::mem::size_of::<$t>();
+                    if bytes.len() > size {
+                        return Err(OverflowError::new(stringify!($t), bytes.len(), size));
+                    }
+                    Ok(bytes.iter().fold(0, |acc, x| (acc << 8) | *x as $t))
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_base256!(u64, u128, usize);
+
+impl<const N: usize> From<[u8; N]> for Base256 {
+    fn from(bytes: [u8; N]) -> Self {
+        Base256::new(bytes.to_vec())
+    }
+}
+
+impl Base256 {
+    /// Construct from big-endian bytes, the internal representation.
+    pub fn from_be_bytes(bytes: &[u8]) -> Self {
+        Base256::new(bytes.to_vec())
+    }
+
+    /// Construct from little-endian bytes, i.e. the least significant byte first.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// assert_eq!(Base256::from_le_bytes(&[2, 1]), Base256::from_be_bytes(&[1, 2]));
+    /// ```
+    pub fn from_le_bytes(bytes: &[u8]) -> Self {
+        Base256::new(bytes.iter().rev().copied().collect())
+    }
+
+    /// Big-endian bytes of the value, including any leading zeros.
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        self.inner.clone()
+    }
+
+    /// Little-endian bytes of the value, including any leading zeros as trailing zeros.
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        self.inner.iter().rev().copied().collect()
+    }
+
+    /// Machine words of the value, least significant word first. Zero has no digits.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let value = Base256::new(vec![1, 0, 0, 0, 0, 0, 0, 0, 2]);
+    /// assert_eq!(value.to_u64_digits(), vec![2, 1]);
+    /// assert_eq!(Base256::from_u64_digits(&[2, 1]), value);
+    /// ```
+    pub fn to_u64_digits(&self) -> Vec<u64> {
+        self.significant_bytes()
+            .rchunks(8)
+            .map(|chunk| chunk.iter().fold(0, |acc, x| (acc << 8) | *x as u64))
+            .collect()
+    }
+
+    /// Construct from machine words, least significant word first.
+    pub fn from_u64_digits(digits: &[u64]) -> Self {
+        Base256::new(digits.iter().rev().flat_map(|x| x.to_be_bytes()).collect()).with_width(1)
+    }
+
+    /// 32 bit words of the value, least significant word first. Zero has no digits.
+    pub fn to_u32_digits(&self) -> Vec<u32> {
+        self.significant_bytes()
+            .rchunks(4)
+            .map(|chunk| chunk.iter().fold(0, |acc, x| (acc << 8) | *x as u32))
+            .collect()
+    }
+
+    /// Construct from 32 bit words, least significant word first.
+    pub fn from_u32_digits(digits: &[u32]) -> Self {
+        Base256::new(digits.iter().rev().flat_map(|x| x.to_be_bytes()).collect()).with_width(1)
+    }
+
+    /// Fixed width big-endian array of the value, left-padded with zeros.
+    ///
+    /// Errors if the value has more than `N` significant bytes; leading zeros beyond `N` are
+    /// dropped.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// assert_eq!(Base256::new(vec![1, 2]).to_array::<4>(), Ok([0, 0, 1, 2]));
+    /// assert_eq!(Base256::new(vec![0, 0, 1, 2]).to_array::<2>(), Ok([1, 2]));
+    /// assert!(Base256::new(vec![1, 2, 3]).to_array::<2>().is_err());
+    /// ```
+    pub fn to_array<const N: usize>(&self) -> Result<[u8; N], OverflowError> {
+        self.to_array_for("array")
+    }
+
+    /// `to_array`, naming `target` in the overflow error.
+    pub(crate) fn to_array_for<const N: usize>(
+        &self,
+        target: &'static str,
+    ) -> Result<[u8; N], OverflowError> {
+        let bytes = self.significant_bytes();
+        if bytes.len() > N {
+            return Err(OverflowError::new(target, bytes.len(), N));
+        }
+        let mut res = [0; N];
+        res[N - bytes.len()..].copy_from_slice(bytes);
+        Ok(res)
+    }
+
+    /// Closest `f64` to the numeric value, or infinity when the value is too large.
+    ///
+    /// Lossy, intended for logging and approximate metrics.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// assert_eq!(Base256::new(vec![1, 0]).to_f64(), 256.0);
+    /// assert_eq!(Base256::new(vec![255; 32]).to_f64(), 2f64.powi(256));
+    /// assert_eq!(Base256::new(vec![255; 200]).to_f64(), f64::INFINITY);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        let bytes = self.significant_bytes();
+        if bytes.len() <= 16 {
+            return bytes.iter().fold(0u128, |acc, x| (acc << 8) | *x as u128) as f64;
+        }
+        let shift = 8 * (bytes.len() - 16);
+        if shift > 1024 {
+            return f64::INFINITY;
+        }
+        let mut top = bytes[..16].iter().fold(0u128, |acc, x| (acc << 8) | *x as u128);
+        // Fold the discarded bytes into a sticky bit so the u128 -> f64 cast rounds correctly.
+        if bytes[16..].iter().any(|x| *x != 0) {
+            top |= 1;
+        }
+        top as f64 * pow2(shift)
+    }
+
+    /// Share of a `byte_length` keyspace below this value, i.e. value / 2^(8 * byte_length),
+    /// in `[0, 1)`.
+    ///
+    /// Values wider than `byte_length` wrap, as with `wrapped_add`.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// assert_eq!(Base256::new(vec![64, 0]).fraction_of(2), 0.25);
+    /// assert_eq!(Base256::new(vec![1, 128]).fraction_of(1), 0.5);
+    /// ```
+    pub fn fraction_of(&self, byte_length: usize) -> f64 {
+        let bytes = self.wrapped_bytes(byte_length);
+        let zeros = bytes.iter().take_while(|x| **x == 0).count();
+        let top = core::cmp::min(bytes.len() - zeros, 16);
+        let numerator = bytes[zeros..zeros + top]
+            .iter()
+            .fold(0u128, |acc, x| (acc << 8) | *x as u128);
+        let fraction = numerator as f64 / pow2(8 * (zeros + top));
+        // Values within half an ulp of the full keyspace would otherwise round up to 1.0.
+        fraction.min(1.0 - f64::EPSILON / 2.0)
+    }
+
+    /// The `byte_length` byte position a share `fraction` of the way round the keyspace,
+    /// `floor(fraction * 2^(8 * byte_length))`, and the inverse of [`Base256::fraction_of`].
+    ///
+    /// Exact for every `f64`, so positions with up to 53 significant bits round-trip. Fractions
+    /// of 1 or more wrap round the ring. Panics if `fraction` is negative, infinite or NaN.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// assert_eq!(Base256::from_fraction(0.25, 2), Base256::new(vec![64, 0]));
+    /// assert_eq!(Base256::from_fraction(1.5, 1), Base256::new(vec![128]));
+    /// // 270 degrees round a ring drawn as a circle.
+    /// assert_eq!(Base256::from_fraction(270.0 / 360.0, 1), Base256::new(vec![192]));
+    /// ```
+    pub fn from_fraction(fraction: f64, byte_length: usize) -> Self {
+        if !fraction.is_finite() || fraction < 0.0 {
+            panic!("Fraction must be finite and non-negative")
+        }
+        let bits = fraction.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i64;
+        let mut mantissa = bits & ((1 << 52) - 1);
+        // Bit `j` of the mantissa has weight 2^(j + shift) once scaled by 2^(8 * byte_length).
+        let mut shift = 8 * byte_length as i64 - 1074;
+        if exponent > 0 {
+            mantissa |= 1 << 52;
+            shift += exponent - 1;
+        }
+        let mut res = vec![0u8; byte_length];
+        for j in 0..53 {
+            let position = j + shift;
+            if (mantissa >> j) & 1 == 1 && (0..8 * byte_length as i64).contains(&position) {
+                let position = position as usize;
+                res[byte_length - 1 - position / 8] |= 1 << (position % 8);
+            }
+        }
+        Base256::new(res)
+    }
+}
+
+/// `2^exp`, built from its bit pattern since `f64::powi` needs std. Infinity above the f64 range.
+fn pow2(exp: usize) -> f64 {
+    if exp > 1023 {
+        return f64::INFINITY;
+    }
+    f64::from_bits((exp as u64 + 1023) << 52)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_primitive() {
+        assert_eq!(Base256::from(0u128), Base256::new(vec![0]));
+        assert_eq!(Base256::from(255u16), Base256::new(vec![255]));
+        assert_eq!(Base256::from(0x1_0000usize), Base256::new(vec![1, 0, 0]));
+        assert_eq!(Base256::from(u128::MAX), Base256::new(vec![255; 16]));
+    }
+
+    #[test]
+    fn test_try_from_base256() {
+        assert_eq!(u128::try_from(&Base256::empty()), Ok(0));
+        assert_eq!(u64::try_from(&Base256::from(u64::MAX)), Ok(u64::MAX));
+        assert_eq!(usize::try_from(&Base256::new(vec![0; 20])), Ok(0));
+        let err = u64::try_from(&Base256::new(vec![0, 1, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap_err();
+        assert_eq!(err.required_bytes(), 9);
+        assert_eq!(err.to_string(), "value requires 9 bytes but u64 holds 8");
+    }
+
+    #[test]
+    fn test_array_round_trip() {
+        let hash = [7u8; 32];
+        assert_eq!(Base256::from(hash).to_array::<32>(), Ok(hash));
+        assert_eq!(Base256::empty().to_array::<2>(), Ok([0, 0]));
+        let err = Base256::from([1u8; 33]).to_array::<32>().unwrap_err();
+        assert_eq!(err.to_string(), "value requires 33 bytes but array holds 32");
+    }
+
+    #[test]
+    fn test_endian_round_trip() {
+        let value = Base256::from(0x0102_0304u32);
+        assert_eq!(value.to_le_bytes(), vec![4, 3, 2, 1]);
+        assert_eq!(value.to_be_bytes(), vec![1, 2, 3, 4]);
+        assert_eq!(Base256::from_le_bytes(&value.to_le_bytes()), value);
+        assert_eq!(Base256::from_le_bytes(&[5, 0]), Base256::new(vec![0, 5]));
+    }
+
+    #[test]
+    fn test_word_digits() {
+        assert!(Base256::new(vec![0, 0]).to_u64_digits().is_empty());
+        assert_eq!(Base256::from_u64_digits(&[]), Base256::new(vec![0]));
+        let value = Base256::from(u128::MAX - 1);
+        assert_eq!(value.to_u64_digits(), vec![u64::MAX - 1, u64::MAX]);
+        assert_eq!(value.to_u32_digits(), vec![u32::MAX - 1, u32::MAX, u32::MAX, u32::MAX]);
+        assert_eq!(Base256::from_u32_digits(&value.to_u32_digits()), value);
+        assert_eq!(Base256::from_u64_digits(&[5, 0]), Base256::new(vec![5]));
+    }
+
+    #[test]
+    fn test_to_f64() {
+        assert_eq!(Base256::empty().to_f64(), 0.0);
+        assert_eq!(Base256::from(u64::MAX).to_f64(), u64::MAX as f64);
+        // 2^136 + 2^83 + 1 lies just above the midpoint of two doubles, so rounds up.
+        let mut bytes = vec![0; 18];
+        bytes[0] = 1;
+        bytes[7] = 8;
+        bytes[17] = 1;
+        assert_eq!(Base256::new(bytes).to_f64(), 2f64.powi(136) + 2f64.powi(84));
+        assert_eq!(Base256::new(vec![255; 128]).to_f64(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_fraction_of() {
+        assert_eq!(Base256::empty().fraction_of(32), 0.0);
+        assert_eq!(Base256::new(vec![0]).fraction_of(0), 0.0);
+        assert_eq!(Base256::new(vec![128]).fraction_of(32), 0.5 / 2f64.powi(248));
+        assert!(Base256::new(vec![255; 32]).fraction_of(32) < 1.0);
+    }
+
+    #[test]
+    fn test_from_fraction_inverts_fraction_of() {
+        for value in [0u64, 1, 0x8000_0000_0000, 0xdead_beef_cafe, 0xffff_ffff_ffff] {
+            let position = Base256::new(value.to_be_bytes()[2..].to_vec());
+            assert_eq!(Base256::from_fraction(position.fraction_of(6), 6), position);
+        }
+        let top = Base256::new(vec![255; 32]);
+        let approx = Base256::from_fraction(top.fraction_of(32), 32);
+        assert_eq!(approx.len(), 32);
+        assert_eq!(approx.leading_zero_bits(), 0);
+        assert_eq!(Base256::from_fraction(f64::MIN_POSITIVE, 2), Base256::new(vec![0, 0]));
+        assert_eq!(Base256::from_fraction(-0.0, 1), Base256::new(vec![0]));
+        assert_eq!(Base256::from_fraction(0.5, 0), Base256::empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Fraction must be finite and non-negative")]
+    fn test_from_fraction_rejects_nan() {
+        Base256::from_fraction(f64::NAN, 4);
+    }
+}
@@ -0,0 +1,313 @@
+use crate::Base256;
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+
+/// Byte `i` of `bytes` when left-padded with zeros to `width` bytes.
+fn padded_byte(bytes: &[u8], width: usize, i: usize) -> u8 {
+    match (i + bytes.len()).checked_sub(width) {
+        Some(index) => bytes[index],
+        None => 0,
+    }
+}
+
+/// Candidate in the `k_closest` heap, ordered by distance to `target` so the farthest is on top.
+struct Closest<'a, T> {
+    target: &'a Base256,
+    item: T,
+}
+
+impl<T: Borrow<Base256>> Ord for Closest<'_, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Base256::distance_cmp(self.item.borrow(), other.item.borrow(), self.target)
+    }
+}
+
+impl<T: Borrow<Base256>> PartialOrd for Closest<'_, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Borrow<Base256>> PartialEq for Closest<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Borrow<Base256>> Eq for Closest<'_, T> {}
+
+/// Sort key ordering IDs by XOR distance to a shared target, closest first, i.e. as a
+/// `BinaryHeap` element or with `min_by_key` over borrowed IDs. Keys are only meaningful
+/// against keys for the same target.
+///
+/// The key borrows the ID, so `sort_by_key` needs a slice of references; for a slice of IDs use
+/// [`Base256::sort_by_distance_to`].
+/// ```
+/// use byte_arithmetic::{Base256, DistanceTo};
+/// use std::cmp::Reverse;
+/// use std::collections::BinaryHeap;
+/// let target = Base256::new(vec![0x10]);
+/// let peers = [0x80, 0x11, 0x30].map(|x| Base256::new(vec![x]));
+/// let mut refs: Vec<&Base256> = peers.iter().collect();
+/// refs.sort_by_key(|x| DistanceTo::new(&target, x));
+/// assert_eq!(refs, [&peers[1], &peers[2], &peers[0]]);
+/// let mut heap: BinaryHeap<_> =
+///     peers.iter().map(|x| Reverse(DistanceTo::new(&target, x))).collect();
+/// assert_eq!(heap.pop().map(|x| x.0.id()), Some(&peers[1]));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DistanceTo<'a> {
+    target: &'a Base256,
+    id: &'a Base256,
+}
+
+impl<'a> DistanceTo<'a> {
+    pub fn new(target: &'a Base256, id: &'a Base256) -> Self {
+        DistanceTo { target, id }
+    }
+
+    pub fn id(&self) -> &'a Base256 {
+        self.id
+    }
+}
+
+impl Ord for DistanceTo<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Base256::distance_cmp(self.id, other.id, self.target)
+    }
+}
+
+impl PartialOrd for DistanceTo<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for DistanceTo<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for DistanceTo<'_> {}
+
+impl Base256 {
+    /// Kademlia XOR distance, at the width of the wider operand.
+    ///
+    /// Operands are aligned at their least significant byte, so a shorter ID reads as if it had
+    /// leading zeros. `^` instead pairs bytes from the most significant end; for equal lengths the
+    /// two agree.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let a = Base256::new(vec![0x0f, 0xf0]);
+    /// assert_eq!(a.xor_distance(&Base256::new(vec![0xff])), Base256::new(vec![0x0f, 0x0f]));
+    /// assert_eq!(a.xor_distance(&a), Base256::new(vec![0, 0]));
+    /// ```
+    pub fn xor_distance(&self, other: &Self) -> Self {
+        let width = self.len().max(other.len());
+        Base256::new(
+            (0..width)
+                .map(|i| padded_byte(self, width, i) ^ padded_byte(other, width, i))
+                .collect::<Vec<u8>>(),
+        )
+    }
+
+    /// Order `a` and `b` by XOR distance to `target`, closest first, without allocating.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// use std::cmp::Ordering;
+    /// let target = Base256::new(vec![0b1000_0000]);
+    /// let near = Base256::new(vec![0b1000_0001]);
+    /// let far = Base256::new(vec![0b0000_0000]);
+    /// assert_eq!(Base256::distance_cmp(&near, &far, &target), Ordering::Less);
+    /// ```
+    pub fn distance_cmp(a: &Self, b: &Self, target: &Self) -> Ordering {
+        let width = a.len().max(b.len()).max(target.len());
+        (0..width)
+            .map(|i| {
+                let t = padded_byte(target, width, i);
+                (padded_byte(a, width, i) ^ t).cmp(&(padded_byte(b, width, i) ^ t))
+            })
+            .find(|x| *x != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }
+
+    /// Sort `peers` in place by XOR distance to `target`, closest first, without cloning or
+    /// allocating distances. Numerically equal IDs keep their order.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let target = Base256::new(vec![0x10]);
+    /// let mut peers = [0x80, 0x11, 0x30].map(|x| Base256::new(vec![x]));
+    /// Base256::sort_by_distance_to(&target, &mut peers);
+    /// assert_eq!(peers, [0x11, 0x30, 0x80].map(|x| Base256::new(vec![x])));
+    /// ```
+    pub fn sort_by_distance_to(target: &Base256, peers: &mut [Base256]) {
+        peers.sort_by(|a, b| Base256::distance_cmp(a, b, target));
+    }
+
+    /// The `k` candidates closest to `target` by XOR distance, closest first.
+    ///
+    /// Keeps a heap of at most `k` candidates and compares in place, so no distance is
+    /// allocated. Candidates may be owned or borrowed; borrowed ones are returned as they came.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let target = Base256::new(vec![0x10]);
+    /// let peers: Vec<Base256> = [0x00, 0x11, 0xff, 0x14].map(|x| Base256::new(vec![x])).into();
+    /// let closest = Base256::k_closest(&target, &peers, 2);
+    /// assert_eq!(closest, vec![&Base256::new(vec![0x11]), &Base256::new(vec![0x14])]);
+    /// ```
+    pub fn k_closest<T, I>(target: &Base256, candidates: I, k: usize) -> Vec<T>
+    where
+        T: Borrow<Base256>,
+        I: IntoIterator<Item = T>,
+    {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut heap = BinaryHeap::with_capacity(k);
+        for item in candidates {
+            let candidate = Closest { target, item };
+            if heap.len() < k {
+                heap.push(candidate);
+            } else if let Some(mut farthest) = heap.peek_mut() {
+                if candidate < *farthest {
+                    *farthest = candidate;
+                }
+            }
+        }
+        heap.into_sorted_vec().into_iter().map(|x| x.item).collect()
+    }
+
+    /// Index of the highest bit in which `self` and `other` differ, counting from the least
+    /// significant bit, i.e. `floor(log2(distance))`. `None` if they are numerically equal.
+    ///
+    /// A peer with bucket index `i` lies at an XOR distance in `2^i..2^(i + 1)`, and belongs to
+    /// k-bucket `i`.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let local = Base256::new(vec![0b0000_0001, 0b1010_0000]);
+    /// assert_eq!(local.bucket_index(&Base256::new(vec![0b0000_0001, 0b1000_0000])), Some(5));
+    /// assert_eq!(local.bucket_index(&Base256::new(vec![0b1000_0001, 0b1010_0000])), Some(15));
+    /// assert_eq!(local.bucket_index(&local), None);
+    /// ```
+    pub fn bucket_index(&self, other: &Self) -> Option<usize> {
+        let width = self.len().max(other.len());
+        (0..width).find_map(|i| {
+            let diff = padded_byte(self, width, i) ^ padded_byte(other, width, i);
+            if diff == 0 {
+                return None;
+            }
+            Some(8 * (width - i - 1) + 7 - diff.leading_zeros() as usize)
+        })
+    }
+
+    /// Number of leading bits that `self` and `other` share, as their bytes are stored, up to the
+    /// length of the shorter one.
+    ///
+    /// For IDs of the same length this is `8 * len - 1 - bucket_index`, or `8 * len` when equal.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let a = Base256::new(vec![0xab, 0b1100_0000]);
+    /// assert_eq!(a.common_prefix_bits(&Base256::new(vec![0xab, 0b1101_0000])), 11);
+    /// assert_eq!(a.common_prefix_bytes(&Base256::new(vec![0xab, 0b1101_0000])), 1);
+    /// assert_eq!(a.common_prefix_bits(&Base256::new(vec![0xab])), 8);
+    /// ```
+    pub fn common_prefix_bits(&self, other: &Self) -> usize {
+        let bytes = self.common_prefix_bytes(other);
+        match (self.get(bytes), other.get(bytes)) {
+            (Some(x), Some(y)) => 8 * bytes + (x ^ y).leading_zeros() as usize,
+            _ => 8 * bytes,
+        }
+    }
+
+    /// Number of leading bytes that `self` and `other` share, up to the length of the shorter one.
+    pub fn common_prefix_bytes(&self, other: &Self) -> usize {
+        self.iter().zip(other.iter()).take_while(|(x, y)| x == y).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_xor_distance_matches_u64() {
+        for (a, b) in [(0u64, 0u64), (1, 0x100), (0xdead, 0xbeef_00ff), (u64::MAX, 7)] {
+            let distance = Base256::from(a).xor_distance(&Base256::from(b));
+            assert_eq!(u64::try_from(&distance), Ok(a ^ b));
+        }
+    }
+
+    #[test]
+    fn test_bucket_index_matches_log2() {
+        for (a, b) in [(0u64, 1u64), (0, 0x80), (0x1234, 0x1235), (5, u64::MAX), (0x100, 0)] {
+            let index = Base256::from(a).bucket_index(&Base256::from(b));
+            assert_eq!(index, Some(63 - (a ^ b).leading_zeros() as usize));
+        }
+        let short = Base256::new(vec![7]);
+        assert_eq!(short.bucket_index(&Base256::new(vec![0, 0, 7])), None);
+    }
+
+    #[test]
+    fn test_common_prefix() {
+        let a = Base256::new(vec![0x12, 0x34]);
+        assert_eq!(a.common_prefix_bits(&a), 16);
+        assert_eq!(a.common_prefix_bits(&Base256::new(vec![0x92, 0x34])), 0);
+        assert_eq!(a.common_prefix_bits(&Base256::empty()), 0);
+        let b = Base256::new(vec![0x12, 0x35]);
+        assert_eq!(a.common_prefix_bits(&b), 15);
+        assert_eq!(a.common_prefix_bits(&b), 16 - 1 - a.bucket_index(&b).unwrap());
+    }
+
+    #[test]
+    fn test_k_closest_matches_sort() {
+        let target = Base256::new(vec![0x5a, 0xa5]);
+        let peers: Vec<Base256> = (0..200u32)
+            .map(|x| Base256::from(((x * 7919) % 65536) as u16))
+            .collect();
+        let mut sorted = peers.clone();
+        sorted.sort_by(|a, b| Base256::distance_cmp(a, b, &target));
+        assert_eq!(Base256::k_closest(&target, peers.clone(), 5), sorted[..5].to_vec());
+        assert_eq!(Base256::k_closest(&target, &peers, 500).len(), 200);
+        assert!(Base256::k_closest(&target, &peers, 0).is_empty());
+    }
+
+    #[test]
+    fn test_distance_to_matches_distance_cmp() {
+        let target = Base256::new(vec![0xa5]);
+        let mut peers: Vec<Base256> = (0..=255u8).map(|x| Base256::new(vec![x])).collect();
+        let mut refs: Vec<&Base256> = peers.iter().collect();
+        refs.sort_by_key(|x| DistanceTo::new(&target, x));
+        let sorted: Vec<Base256> = refs.into_iter().cloned().collect();
+        Base256::sort_by_distance_to(&target, &mut peers);
+        assert_eq!(sorted, peers);
+        assert_eq!(peers[0], target);
+        let wide = Base256::new(vec![0, 0xa5]);
+        assert_eq!(DistanceTo::new(&target, &wide), DistanceTo::new(&target, &target));
+    }
+
+    #[test]
+    fn test_distance_cmp_sorts_by_closeness() {
+        let target = Base256::new(vec![0x50]);
+        let mut peers = vec![
+            Base256::new(vec![0, 0x00]),
+            Base256::new(vec![0x51]),
+            Base256::new(vec![1, 0x50]),
+            Base256::new(vec![0x40]),
+        ];
+        peers.sort_by(|a, b| Base256::distance_cmp(a, b, &target));
+        assert_eq!(
+            peers,
+            vec![
+                Base256::new(vec![0x51]),
+                Base256::new(vec![0x40]),
+                Base256::new(vec![0, 0x00]),
+                Base256::new(vec![1, 0x50]),
+            ]
+        );
+        assert_eq!(Base256::distance_cmp(&target, &target, &target), Ordering::Equal);
+    }
+}
@@ -0,0 +1,172 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::{Base256, ParseError};
+
+/// Alphabet used by [`Base256::to_base32`] and [`Base256::from_base32`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum Base32Alphabet {
+    /// RFC 4648 `A-Z2-7`.
+    Rfc4648,
+    /// RFC 4648 extended hex, `0-9A-V`, which preserves sort order.
+    Rfc4648Hex,
+    /// Crockford's `0-9A-Z` without `I`, `L`, `O` and `U`. Decoding is case-insensitive, reads
+    /// `I`/`L` as `1` and `O` as `0`, and ignores hyphens.
+    Crockford,
+}
+
+impl Base32Alphabet {
+    fn symbols(&self) -> &'static [u8; 32] {
+        match self {
+            Base32Alphabet::Rfc4648 => b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567",
+            Base32Alphabet::Rfc4648Hex => b"0123456789ABCDEFGHIJKLMNOPQRSTUV",
+            Base32Alphabet::Crockford => b"0123456789ABCDEFGHJKMNPQRSTVWXYZ",
+        }
+    }
+
+    fn decode(&self, symbol: u8) -> Option<u8> {
+        let symbol = symbol.to_ascii_uppercase();
+        let symbol = match (self, symbol) {
+            (Base32Alphabet::Crockford, b'O') => b'0',
+            (Base32Alphabet::Crockford, b'I') | (Base32Alphabet::Crockford, b'L') => b'1',
+            _ => symbol,
+        };
+        self.symbols()
+            .iter()
+            .position(|x| *x == symbol)
+            .map(|x| x as u8)
+    }
+}
+
+impl Base256 {
+    /// Base32 of the bytes, five bits per symbol, optionally padded with `=` to a multiple of
+    /// eight symbols.
+    /// ```
+    /// use byte_arithmetic::{Base256, Base32Alphabet};
+    /// let value = Base256::from(b"foobar".to_vec());
+    /// assert_eq!(value.to_base32(Base32Alphabet::Rfc4648, true), "MZXW6YTBOI======");
+    /// assert_eq!(value.to_base32(Base32Alphabet::Crockford, false), "CSQPYRK1E8");
+    /// assert_eq!(Base256::from_base32("csqp-yrk1-e8", Base32Alphabet::Crockford), Ok(value));
+    /// ```
+    pub fn to_base32(&self, alphabet: Base32Alphabet, padding: bool) -> String {
+        let symbols = alphabet.symbols();
+        let mut res = String::with_capacity(self.inner.len().div_ceil(5) * 8);
+        let mut buffer: u16 = 0;
+        let mut bits = 0;
+        for byte in self.inner.iter() {
+            buffer = (buffer << 8) | *byte as u16;
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                res.push(symbols[((buffer >> bits) & 0x1f) as usize] as char);
+            }
+        }
+        if bits > 0 {
+            res.push(symbols[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+        }
+        if padding {
+            while res.len() % 8 != 0 {
+                res.push('=');
+            }
+        }
+        res
+    }
+
+    /// Decode Base32, with or without `=` padding. Symbols are case-insensitive.
+    ///
+    /// Errors on a symbol count no encoding produces, or on a final symbol whose unused low bits
+    /// are not zero, so every value has a single accepted spelling up to case and padding.
+    pub fn from_base32(s: &str, alphabet: Base32Alphabet) -> Result<Self, ParseError> {
+        let mut res = Vec::with_capacity(s.len() * 5 / 8);
+        let mut buffer: u16 = 0;
+        let mut bits = 0;
+        let mut last = 0;
+        for (index, symbol) in s.trim_end_matches('=').bytes().enumerate() {
+            if alphabet == Base32Alphabet::Crockford && symbol == b'-' {
+                continue;
+            }
+            let value = alphabet
+                .decode(symbol)
+                .ok_or(ParseError::InvalidDigit { index })?;
+            buffer = (buffer << 5) | value as u16;
+            bits += 5;
+            last = index;
+            if bits >= 8 {
+                bits -= 8;
+                res.push((buffer >> bits) as u8);
+            }
+        }
+        if bits >= 5 {
+            return Err(ParseError::InvalidLength);
+        }
+        if buffer & ((1 << bits) - 1) != 0 {
+            return Err(ParseError::InvalidDigit { index: last });
+        }
+        Ok(Base256::new(res))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rfc4648_vectors() {
+        let cases = [
+            ("", ""),
+            ("f", "MY======"),
+            ("fo", "MZXQ===="),
+            ("foo", "MZXW6==="),
+            ("foob", "MZXW6YQ="),
+            ("fooba", "MZXW6YTB"),
+        ];
+        for (plain, encoded) in cases {
+            let value = Base256::from(plain.as_bytes());
+            assert_eq!(value.to_base32(Base32Alphabet::Rfc4648, true), encoded);
+            assert_eq!(Base256::from_base32(encoded, Base32Alphabet::Rfc4648), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_rfc4648_hex_vector() {
+        let value = Base256::from(b"foobar".as_slice());
+        assert_eq!(value.to_base32(Base32Alphabet::Rfc4648Hex, true), "CPNMUOJ1E8======");
+        assert_eq!(value.to_base32(Base32Alphabet::Rfc4648Hex, false), "CPNMUOJ1E8");
+    }
+
+    #[test]
+    fn test_crockford_aliases() {
+        let value = Base256::new(vec![0x08, 0x42]);
+        let encoded = value.to_base32(Base32Alphabet::Crockford, false);
+        assert_eq!(encoded, "1110");
+        assert_eq!(Base256::from_base32("iL1o", Base32Alphabet::Crockford), Ok(value));
+        assert_eq!(
+            Base256::from_base32("U", Base32Alphabet::Crockford),
+            Err(ParseError::InvalidDigit { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_rejects_impossible_lengths() {
+        for encoded in ["M", "MZX", "MZXW6Y", "MZX=====", "MZXW6Y=="] {
+            assert_eq!(
+                Base256::from_base32(encoded, Base32Alphabet::Rfc4648),
+                Err(ParseError::InvalidLength)
+            );
+        }
+    }
+
+    #[test]
+    fn test_rejects_non_zero_trailing_bits() {
+        // "MY" is the only spelling of "f"; "MZ" sets one of the two unused bits.
+        assert_eq!(
+            Base256::from_base32("MZ======", Base32Alphabet::Rfc4648),
+            Err(ParseError::InvalidDigit { index: 1 })
+        );
+        assert_eq!(
+            Base256::from_base32("1-111", Base32Alphabet::Crockford),
+            Err(ParseError::InvalidDigit { index: 4 })
+        );
+        let f = Base256::from(b"f".as_slice());
+        assert_eq!(Base256::from_base32("MY", Base32Alphabet::Rfc4648), Ok(f));
+    }
+}
@@ -0,0 +1,74 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::{Base256, ParseError};
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+impl Base256 {
+    /// Base58 using the Bitcoin alphabet. Each leading zero byte is encoded as a leading `1`.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let value = Base256::new(vec![0, 0, 0x28, 0x7f, 0xb4, 0xcd]);
+    /// assert_eq!(value.to_base58(), "11233QC4");
+    /// assert_eq!(Base256::from_base58("11233QC4"), Ok(value));
+    /// ```
+    pub fn to_base58(&self) -> String {
+        let zeros = self.inner.len() - self.significant_bytes().len();
+        let mut res = "1".repeat(zeros);
+        if zeros < self.inner.len() {
+            res.extend(
+                self.to_radix_digits(58)
+                    .into_iter()
+                    .map(|x| ALPHABET[x as usize] as char),
+            );
+        }
+        res
+    }
+
+    /// Decode Bitcoin alphabet Base58. Each leading `1` is decoded as a leading zero byte.
+    pub fn from_base58(s: &str) -> Result<Self, ParseError> {
+        let digits = s
+            .bytes()
+            .enumerate()
+            .map(|(index, x)| match ALPHABET.iter().position(|a| *a == x) {
+                Some(digit) => Ok(digit as u8),
+                None => Err(ParseError::InvalidDigit { index }),
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+        let zeros = digits.iter().take_while(|x| **x == 0).count();
+        let mut res = vec![0; zeros];
+        if zeros < digits.len() {
+            let value = Base256::from_radix_digits(&digits[zeros..], 58)?;
+            res.extend_from_slice(value.significant_bytes());
+        }
+        Ok(Base256::new(res))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base58_vectors() {
+        assert_eq!(Base256::from(b"Hello World!".to_vec()).to_base58(), "2NEpo7TZRRrLZSi2U");
+        assert_eq!(Base256::new(vec![0x00, 0x00, 0x00]).to_base58(), "111");
+        assert_eq!(Base256::empty().to_base58(), "");
+        assert_eq!(Base256::new(vec![0x3a]).to_base58(), "21");
+    }
+
+    #[test]
+    fn test_base58_round_trip() {
+        for bytes in [vec![], vec![0], vec![0, 255], vec![255; 32], vec![0, 0, 1, 0]] {
+            let value = Base256::new(bytes);
+            assert_eq!(Base256::from_base58(&value.to_base58()), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_base58_invalid() {
+        assert_eq!(Base256::from_base58("1O"), Err(ParseError::InvalidDigit { index: 1 }));
+        assert_eq!(Base256::from_base58("0"), Err(ParseError::InvalidDigit { index: 0 }));
+    }
+}
@@ -0,0 +1,65 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::{Base256, ParseError};
+use sha2::{Digest, Sha256};
+
+fn checksum(data: &[u8]) -> [u8; 4] {
+    let hash = Sha256::digest(Sha256::digest(data));
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+impl Base256 {
+    /// Base58Check, prefixing the bytes with `version` and appending the first four bytes of
+    /// their double SHA256.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let hash = Base256::from_hex("f54a5851e9372b87810a8e60cdd2e7cfd80b6e31").unwrap();
+    /// assert_eq!(hash.to_base58check(0), "1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAs");
+    /// assert_eq!(Base256::from_base58check("1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAs"), Ok((0, hash)));
+    /// ```
+    pub fn to_base58check(&self, version: u8) -> String {
+        let mut data = Vec::with_capacity(self.inner.len() + 5);
+        data.push(version);
+        data.extend_from_slice(&self.inner);
+        let check = checksum(&data);
+        data.extend_from_slice(&check);
+        Base256::new(data).to_base58()
+    }
+
+    /// Decode Base58Check into its version byte and payload, verifying the checksum.
+    pub fn from_base58check(s: &str) -> Result<(u8, Self), ParseError> {
+        let data: Vec<u8> = Base256::from_base58(s)?.into();
+        if data.len() < 5 {
+            return Err(ParseError::InvalidLength);
+        }
+        let (body, check) = data.split_at(data.len() - 4);
+        if checksum(body) != check {
+            return Err(ParseError::InvalidChecksum);
+        }
+        Ok((body[0], Base256::new(body[1..].to_vec())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base58check_round_trip() {
+        let mut bytes = vec![7; 32];
+        bytes[..2].copy_from_slice(&[0, 0]);
+        let id = Base256::new(bytes);
+        let encoded = id.to_base58check(0x80);
+        assert_eq!(Base256::from_base58check(&encoded), Ok((0x80, id)));
+    }
+
+    #[test]
+    fn test_base58check_errors() {
+        let mut encoded = Base256::new(vec![1, 2, 3]).to_base58check(5).into_bytes();
+        let last = encoded.len() - 1;
+        encoded[last] = if encoded[last] == b'2' { b'3' } else { b'2' };
+        let corrupted = String::from_utf8(encoded).unwrap();
+        assert_eq!(Base256::from_base58check(&corrupted), Err(ParseError::InvalidChecksum));
+        assert_eq!(Base256::from_base58check("1111"), Err(ParseError::InvalidLength));
+    }
+}
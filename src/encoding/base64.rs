@@ -0,0 +1,152 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::{Base256, ParseError};
+
+/// Alphabet used by [`Base256::to_base64`] and [`Base256::from_base64`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum Base64Alphabet {
+    /// RFC 4648 `A-Za-z0-9+/`.
+    Standard,
+    /// RFC 4648 URL and filename safe `A-Za-z0-9-_`.
+    UrlSafe,
+}
+
+impl Base64Alphabet {
+    fn symbols(&self) -> &'static [u8; 64] {
+        match self {
+            Base64Alphabet::Standard => {
+                b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/"
+            }
+            Base64Alphabet::UrlSafe => {
+                b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"
+            }
+        }
+    }
+}
+
+impl Base256 {
+    /// Base64 of the bytes, optionally padded with `=` to a multiple of four symbols.
+    /// ```
+    /// use byte_arithmetic::{Base256, Base64Alphabet};
+    /// let value = Base256::new(vec![0xfb, 0xff]);
+    /// assert_eq!(value.to_base64(Base64Alphabet::Standard, true), "+/8=");
+    /// assert_eq!(value.to_base64(Base64Alphabet::UrlSafe, false), "-_8");
+    /// assert_eq!(Base256::from_base64("-_8", Base64Alphabet::UrlSafe), Ok(value));
+    /// ```
+    pub fn to_base64(&self, alphabet: Base64Alphabet, padding: bool) -> String {
+        let symbols = alphabet.symbols();
+        let mut res = String::with_capacity(self.inner.len().div_ceil(3) * 4);
+        for chunk in self.inner.chunks(3) {
+            let block = chunk
+                .iter()
+                .chain(core::iter::repeat(&0))
+                .take(3)
+                .fold(0u32, |acc, x| (acc << 8) | *x as u32);
+            for i in 0..=chunk.len() {
+                res.push(symbols[((block >> (18 - 6 * i)) & 0x3f) as usize] as char);
+            }
+        }
+        if padding {
+            while res.len() % 4 != 0 {
+                res.push('=');
+            }
+        }
+        res
+    }
+
+    /// Decode Base64, with or without `=` padding.
+    ///
+    /// Decoding is strict, so each value has one encoding: padding, if present, must complete the
+    /// last group of four symbols, and the unused low bits of the last symbol must be zero.
+    pub fn from_base64(s: &str, alphabet: Base64Alphabet) -> Result<Self, ParseError> {
+        let symbols = alphabet.symbols();
+        let trimmed = s.trim_end_matches('=');
+        let padded = s.len() != trimmed.len();
+        if trimmed.len() % 4 == 1 || (padded && s.len() != trimmed.len().div_ceil(4) * 4) {
+            return Err(ParseError::InvalidLength);
+        }
+        let mut res = Vec::with_capacity(trimmed.len() * 3 / 4);
+        let mut buffer: u32 = 0;
+        let mut bits = 0;
+        for (index, symbol) in trimmed.bytes().enumerate() {
+            let value = symbols
+                .iter()
+                .position(|x| *x == symbol)
+                .ok_or(ParseError::InvalidDigit { index })?;
+            buffer = (buffer << 6) | value as u32;
+            bits += 6;
+            if bits >= 8 {
+                bits -= 8;
+                res.push((buffer >> bits) as u8);
+            }
+        }
+        if buffer & ((1 << bits) - 1) != 0 {
+            return Err(ParseError::InvalidDigit { index: trimmed.len() - 1 });
+        }
+        Ok(Base256::new(res))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rfc4648_vectors() {
+        let cases = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+        for (plain, encoded) in cases {
+            let value = Base256::from(plain.as_bytes());
+            assert_eq!(value.to_base64(Base64Alphabet::Standard, true), encoded);
+            assert_eq!(Base256::from_base64(encoded, Base64Alphabet::Standard), Ok(value.clone()));
+            let unpadded = encoded.trim_end_matches('=');
+            assert_eq!(value.to_base64(Base64Alphabet::Standard, false), unpadded);
+            assert_eq!(Base256::from_base64(unpadded, Base64Alphabet::Standard), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_base64_errors() {
+        assert_eq!(
+            Base256::from_base64("ab_d", Base64Alphabet::Standard),
+            Err(ParseError::InvalidDigit { index: 2 })
+        );
+        assert_eq!(
+            Base256::from_base64("abcde", Base64Alphabet::UrlSafe),
+            Err(ParseError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn test_rejects_non_zero_trailing_bits() {
+        for encoded in ["Zh", "Zh=="] {
+            assert_eq!(
+                Base256::from_base64(encoded, Base64Alphabet::Standard),
+                Err(ParseError::InvalidDigit { index: 1 })
+            );
+        }
+        assert_eq!(
+            Base256::from_base64("Zm9=", Base64Alphabet::Standard),
+            Err(ParseError::InvalidDigit { index: 2 })
+        );
+    }
+
+    #[test]
+    fn test_rejects_incomplete_or_excess_padding() {
+        for encoded in ["Zg=", "Zg=====", "Zm8==", "Zm9v====", "===="] {
+            assert_eq!(
+                Base256::from_base64(encoded, Base64Alphabet::Standard),
+                Err(ParseError::InvalidLength),
+                "{}",
+                encoded
+            );
+        }
+    }
+}
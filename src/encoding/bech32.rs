@@ -0,0 +1,178 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use crate::{Base256, ParseError};
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+fn polymod(values: impl Iterator<Item = u8>) -> u32 {
+    values.fold(1, |checksum, value| {
+        let top = checksum >> 25;
+        let mut checksum = ((checksum & 0x1ffffff) << 5) ^ value as u32;
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+        checksum
+    })
+}
+
+fn expand_hrp(hrp: &[u8]) -> impl Iterator<Item = u8> + '_ {
+    hrp.iter()
+        .map(|x| x >> 5)
+        .chain(core::iter::once(0))
+        .chain(hrp.iter().map(|x| x & 0x1f))
+}
+
+fn checksum(hrp: &[u8], data: &[u8]) -> [u8; 6] {
+    let value = polymod(expand_hrp(hrp).chain(data.iter().copied()).chain([0; 6])) ^ 1;
+    let mut res = [0; 6];
+    for (i, x) in res.iter_mut().enumerate() {
+        *x = ((value >> (5 * (5 - i))) & 0x1f) as u8;
+    }
+    res
+}
+
+impl Base256 {
+    /// Bech32 (BIP 173) with human-readable part `hrp`, which is lowercased.
+    ///
+    /// The BIP's 90 character limit is not enforced, so long values lose some of the checksum's
+    /// error detection guarantees. Panics if `hrp` is empty or contains characters outside ASCII
+    /// 33 to 126.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let id = Base256::new(vec![0, 1, 2]);
+    /// let encoded = id.to_bech32("node");
+    /// assert_eq!(encoded, "node1qqqsyguvz92");
+    /// assert_eq!(Base256::from_bech32(&encoded), Ok(("node".to_string(), id)));
+    /// ```
+    pub fn to_bech32(&self, hrp: &str) -> String {
+        if hrp.is_empty() || !hrp.bytes().all(|x| (33..=126).contains(&x)) {
+            panic!("Invalid bech32 human-readable part {:?}", hrp)
+        }
+        let hrp = hrp.to_ascii_lowercase();
+        let mut data = Vec::with_capacity((self.inner.len() * 8).div_ceil(5));
+        let mut buffer: u16 = 0;
+        let mut bits = 0;
+        for byte in self.inner.iter() {
+            buffer = (buffer << 8) | *byte as u16;
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                data.push(((buffer >> bits) & 0x1f) as u8);
+            }
+        }
+        if bits > 0 {
+            data.push(((buffer << (5 - bits)) & 0x1f) as u8);
+        }
+        let check = checksum(hrp.as_bytes(), &data);
+        let mut res = hrp;
+        res.push('1');
+        res.extend(data.iter().chain(check.iter()).map(|x| CHARSET[*x as usize] as char));
+        res
+    }
+
+    /// Decode Bech32 into its human-readable part and data, verifying the checksum.
+    pub fn from_bech32(s: &str) -> Result<(String, Self), ParseError> {
+        if let Some(index) = s.bytes().position(|x| !(33..=126).contains(&x)) {
+            return Err(ParseError::InvalidDigit { index });
+        }
+        let upper = s.bytes().any(|x| x.is_ascii_uppercase());
+        if upper {
+            if let Some(index) = s.bytes().position(|x| x.is_ascii_lowercase()) {
+                return Err(ParseError::InvalidDigit { index });
+            }
+        }
+        let s = s.to_ascii_lowercase();
+        let separator = s.rfind('1').ok_or(ParseError::InvalidLength)?;
+        if separator == 0 || s.len() - separator < 7 {
+            return Err(ParseError::InvalidLength);
+        }
+        let hrp = &s[..separator];
+        let data = s[separator + 1..]
+            .bytes()
+            .enumerate()
+            .map(|(index, x)| match CHARSET.iter().position(|c| *c == x) {
+                Some(value) => Ok(value as u8),
+                None => Err(ParseError::InvalidDigit {
+                    index: index + separator + 1,
+                }),
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+        if polymod(expand_hrp(hrp.as_bytes()).chain(data.iter().copied())) != 1 {
+            return Err(ParseError::InvalidChecksum);
+        }
+        let data = &data[..data.len() - 6];
+        let mut res = Vec::with_capacity(data.len() * 5 / 8);
+        let mut buffer: u16 = 0;
+        let mut bits = 0;
+        for value in data {
+            buffer = (buffer << 5) | *value as u16;
+            bits += 5;
+            if bits >= 8 {
+                bits -= 8;
+                res.push((buffer >> bits) as u8);
+            }
+        }
+        if bits >= 5 {
+            return Err(ParseError::InvalidLength);
+        }
+        if buffer & ((1 << bits) - 1) != 0 {
+            return Err(ParseError::InvalidDigit { index: separator + data.len() });
+        }
+        Ok((hrp.to_string(), Base256::new(res)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bip173_valid_checksums() {
+        for valid in ["A12UEL5L", "a12uel5l", "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw"] {
+            assert!(Base256::from_bech32(valid).is_ok(), "{}", valid);
+        }
+    }
+
+    #[test]
+    fn test_bech32_errors() {
+        assert_eq!(Base256::from_bech32("pzry9x0s0muk"), Err(ParseError::InvalidLength));
+        assert_eq!(Base256::from_bech32("1pzry9x0s0muk"), Err(ParseError::InvalidLength));
+        assert_eq!(Base256::from_bech32("a12UEL5L"), Err(ParseError::InvalidDigit { index: 0 }));
+        assert_eq!(Base256::from_bech32("a12uel5m"), Err(ParseError::InvalidChecksum));
+        assert_eq!(
+            Base256::from_bech32("x1b4n0q5v"),
+            Err(ParseError::InvalidDigit { index: 2 })
+        );
+    }
+
+    /// `a1` followed by `data` and a valid checksum.
+    fn with_checksum(data: &[u8]) -> String {
+        let check = checksum(b"a", data);
+        let symbols = data.iter().chain(check.iter()).map(|x| CHARSET[*x as usize] as char);
+        "a1".chars().chain(symbols).collect()
+    }
+
+    #[test]
+    fn test_rejects_stray_trailing_bits() {
+        let zero = Ok(("a".to_string(), Base256::new(vec![0])));
+        assert_eq!(Base256::from_bech32(&with_checksum(&[0, 0])), zero);
+        assert_eq!(
+            Base256::from_bech32(&with_checksum(&[0, 1])),
+            Err(ParseError::InvalidDigit { index: 3 })
+        );
+        assert_eq!(Base256::from_bech32(&with_checksum(&[0])), Err(ParseError::InvalidLength));
+    }
+
+    #[test]
+    fn test_bech32_round_trip() {
+        for bytes in [vec![], vec![0], vec![255; 32], vec![1, 2, 3, 4, 5]] {
+            let value = Base256::new(bytes);
+            let encoded = value.to_bech32("ID");
+            assert!(encoded.starts_with("id1"));
+            assert_eq!(Base256::from_bech32(&encoded), Ok(("id".to_string(), value)));
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! Text encodings of the bytes of a value, each behind a feature of the same name.
+
+#[cfg(feature = "base32")]
+mod base32;
+#[cfg(feature = "base58")]
+mod base58;
+#[cfg(feature = "base58check")]
+mod base58check;
+#[cfg(feature = "base64")]
+mod base64;
+#[cfg(feature = "bech32")]
+mod bech32;
+
+#[cfg(feature = "base32")]
+pub use base32::Base32Alphabet;
+#[cfg(feature = "base64")]
+pub use base64::Base64Alphabet;
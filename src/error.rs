@@ -0,0 +1,90 @@
+use core::fmt;
+
+/// Error returned when a value needs more bytes than the conversion target can hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverflowError {
+    target: &'static str,
+    required: usize,
+    available: usize,
+}
+
+impl OverflowError {
+    pub(crate) fn new(target: &'static str, required: usize, available: usize) -> Self {
+        OverflowError {
+            target,
+            required,
+            available,
+        }
+    }
+
+    /// Significant bytes in the value being converted.
+    pub fn required_bytes(&self) -> usize {
+        self.required
+    }
+
+    /// Bytes available in the conversion target.
+    pub fn available_bytes(&self) -> usize {
+        self.available
+    }
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "value requires {} bytes but {} holds {}",
+            self.required, self.target, self.available
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OverflowError {}
+
+/// Error returned when the result of fixed-width arithmetic cannot be represented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArithmeticError {
+    /// The result needs more bytes than the output holds.
+    Overflow,
+    /// The result would be negative.
+    Underflow,
+}
+
+impl fmt::Display for ArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArithmeticError::Overflow => write!(f, "result does not fit in the output"),
+            ArithmeticError::Underflow => write!(f, "result would be negative"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ArithmeticError {}
+
+/// Error returned when a textual or digit representation cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input contained no digits.
+    Empty,
+    /// The digit at `index` is not valid for the representation.
+    InvalidDigit { index: usize },
+    /// The decoded data is too short or too long for the representation.
+    InvalidLength,
+    /// The embedded checksum does not match the decoded data.
+    InvalidChecksum,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "cannot parse a value from empty input"),
+            ParseError::InvalidDigit { index } => write!(f, "invalid digit at index {}", index),
+            ParseError::InvalidLength => write!(f, "decoded data has the wrong length"),
+            ParseError::InvalidChecksum => write!(f, "checksum does not match"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
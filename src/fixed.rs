@@ -0,0 +1,326 @@
+use crate::{Base256, ByteArithmetic, OverflowError};
+use core::fmt;
+use core::ops::{Add, AddAssign, BitXor, BitXorAssign, Mul, Sub, SubAssign};
+
+/// Fixed width big-endian value of `N` bytes, i.e. a 32byte hash as `FixedBase256<32>`.
+///
+/// Construction and basic arithmetic are `const fn`, so protocol constants can be `const` items.
+/// Arithmetic stays within `N` bytes; use the `wrapping_` or `checked_` form to choose how
+/// overflow is handled. The operators panic on overflow and underflow instead.
+///
+/// No operation allocates, so with `default-features = false` this is the variant to use on
+/// targets without an allocator. Ordering is numeric, since every value has the same width.
+/// ```
+/// use byte_arithmetic::{Base256, FixedBase256};
+/// const GENERATOR: FixedBase256<2> = FixedBase256::from_be_bytes([0x01, 0xff]);
+/// const NEXT: FixedBase256<2> = GENERATOR.wrapping_add(&FixedBase256::from_be_bytes([0, 1]));
+/// assert_eq!(NEXT.to_be_bytes(), [0x02, 0x00]);
+/// assert_eq!(Base256::from(NEXT), Base256::new(vec![0x02, 0x00]));
+/// ```
+#[derive(PartialEq, Ord, PartialOrd, Eq, Clone, Copy, Hash)]
+pub struct FixedBase256<const N: usize> {
+    pub(crate) bytes: [u8; N],
+}
+
+impl<const N: usize> FixedBase256<N> {
+    pub const ZERO: Self = FixedBase256 { bytes: [0; N] };
+    pub const MAX: Self = FixedBase256 { bytes: [255; N] };
+
+    pub const fn from_be_bytes(bytes: [u8; N]) -> Self {
+        FixedBase256 { bytes }
+    }
+
+    pub const fn to_be_bytes(&self) -> [u8; N] {
+        self.bytes
+    }
+
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.bytes
+    }
+
+    /// Sum and whether it overflowed `N` bytes.
+    pub const fn overflowing_add(&self, rhs: &Self) -> (Self, bool) {
+        let mut res = [0u8; N];
+        let mut carry = 0u16;
+        let mut i = N;
+        while i > 0 {
+            i -= 1;
+            let acc = self.bytes[i] as u16 + rhs.bytes[i] as u16 + carry;
+            res[i] = acc as u8;
+            carry = acc >> 8;
+        }
+        (FixedBase256 { bytes: res }, carry > 0)
+    }
+
+    pub const fn wrapping_add(&self, rhs: &Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    pub const fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    /// Difference and whether it underflowed.
+    pub const fn overflowing_sub(&self, rhs: &Self) -> (Self, bool) {
+        let mut res = [0u8; N];
+        let mut borrow = 0i16;
+        let mut i = N;
+        while i > 0 {
+            i -= 1;
+            let mut acc = self.bytes[i] as i16 - rhs.bytes[i] as i16 - borrow;
+            borrow = 0;
+            if acc < 0 {
+                acc += 256;
+                borrow = 1;
+            }
+            res[i] = acc as u8;
+        }
+        (FixedBase256 { bytes: res }, borrow > 0)
+    }
+
+    pub const fn wrapping_sub(&self, rhs: &Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    pub const fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    pub const fn xor(&self, rhs: &Self) -> Self {
+        let mut res = [0u8; N];
+        let mut i = 0;
+        while i < N {
+            res[i] = self.bytes[i] ^ rhs.bytes[i];
+            i += 1;
+        }
+        FixedBase256 { bytes: res }
+    }
+
+    /// Product with a byte and whether it overflowed `N` bytes.
+    pub const fn overflowing_scalar_multiply(&self, value: u8) -> (Self, bool) {
+        let mut res = [0u8; N];
+        let mut carry = 0u16;
+        let mut i = N;
+        while i > 0 {
+            i -= 1;
+            let acc = self.bytes[i] as u16 * value as u16 + carry;
+            res[i] = acc as u8;
+            carry = acc >> 8;
+        }
+        (FixedBase256 { bytes: res }, carry > 0)
+    }
+}
+
+impl<const N: usize> Default for FixedBase256<N> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedBase256<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FixedBase256<{}>(0x{:x})", N, self)
+    }
+}
+
+impl<const N: usize> Add for FixedBase256<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(&rhs).expect("Overflow")
+    }
+}
+
+impl<const N: usize> Sub for FixedBase256<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(&rhs).expect("Underflow")
+    }
+}
+
+impl<const N: usize> BitXor for FixedBase256<N> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.xor(&rhs)
+    }
+}
+
+impl<const N: usize> Mul<u8> for FixedBase256<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: u8) -> Self::Output {
+        ByteArithmetic::scalar_multiply(&self, rhs)
+    }
+}
+
+impl<const N: usize> AddAssign for FixedBase256<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize> SubAssign for FixedBase256<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize> BitXorAssign for FixedBase256<N> {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.xor(&rhs);
+    }
+}
+
+impl<const N: usize> fmt::LowerHex for FixedBase256<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.bytes.iter() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> fmt::UpperHex for FixedBase256<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.bytes.iter() {
+            write!(f, "{:02X}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for FixedBase256<N> {
+    type Error = OverflowError;
+
+    /// Left-pads shorter slices, and errors if the slice has more than `N` significant bytes.
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let zeros = value.iter().take_while(|x| **x == 0).count();
+        let significant = &value[zeros..];
+        if significant.len() > N {
+            return Err(OverflowError::new("FixedBase256", significant.len(), N));
+        }
+        let mut bytes = [0; N];
+        bytes[N - significant.len()..].copy_from_slice(significant);
+        Ok(FixedBase256 { bytes })
+    }
+}
+
+impl<const N: usize> From<FixedBase256<N>> for Base256 {
+    fn from(value: FixedBase256<N>) -> Self {
+        Base256::from(value.bytes)
+    }
+}
+
+impl<const N: usize> TryFrom<&Base256> for FixedBase256<N> {
+    type Error = OverflowError;
+
+    /// Left-pads shorter values, and errors if the value has more than `N` significant bytes.
+    fn try_from(value: &Base256) -> Result<Self, Self::Error> {
+        value
+            .to_array_for("FixedBase256")
+            .map(FixedBase256::from_be_bytes)
+    }
+}
+
+impl<const N: usize> ByteArithmetic for FixedBase256<N> {
+    fn add(&self, rhs: &Self) -> Self {
+        self.checked_add(rhs).expect("Overflow")
+    }
+
+    fn wrapped_add(&self, rhs: &Self, byte_length: usize) -> Self {
+        let mut res = self.wrapping_add(rhs);
+        if byte_length < N {
+            res.bytes[..N - byte_length].fill(0);
+        }
+        res
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        self.checked_sub(rhs).expect("Underflow")
+    }
+
+    fn xor(&self, rhs: &Self) -> Self {
+        FixedBase256::xor(self, rhs)
+    }
+
+    fn scalar_multiply(&self, value: u8) -> Self {
+        match self.overflowing_scalar_multiply(value) {
+            (res, false) => res,
+            (_, true) => panic!("Overflow"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE: FixedBase256<2> = FixedBase256::from_be_bytes([0, 1]);
+
+    #[test]
+    fn test_const_arithmetic() {
+        const WRAPPED: FixedBase256<2> = FixedBase256::<2>::MAX.wrapping_add(&ONE);
+        const BELOW_ZERO: Option<FixedBase256<2>> = FixedBase256::<2>::ZERO.checked_sub(&ONE);
+        assert_eq!(WRAPPED, FixedBase256::ZERO);
+        assert_eq!(BELOW_ZERO, None);
+        assert_eq!(FixedBase256::ZERO.wrapping_sub(&ONE), FixedBase256::MAX);
+        assert_eq!(ONE.xor(&FixedBase256::MAX).to_be_bytes(), [255, 254]);
+    }
+
+    #[test]
+    fn test_matches_base256() {
+        let a = FixedBase256::from_be_bytes([0x12, 0xf0, 0x0f]);
+        let b = FixedBase256::from_be_bytes([0x01, 0x20, 0xff]);
+        assert_eq!(
+            Base256::from(ByteArithmetic::add(&a, &b)),
+            Base256::from(a) + Base256::from(b)
+        );
+        assert_eq!(
+            Base256::from(ByteArithmetic::sub(&a, &b)),
+            Base256::from(a) - Base256::from(b)
+        );
+        assert_eq!(
+            Base256::from(ByteArithmetic::wrapped_add(&a, &b, 2)),
+            Base256::new(vec![0, 0x11, 0x0e])
+        );
+        assert_eq!(Base256::from(a.scalar_multiply(3)), Base256::from(a) * 3);
+    }
+
+    #[test]
+    fn test_operators() {
+        let mut value = FixedBase256::from_be_bytes([0, 0xff]);
+        value += ONE;
+        assert_eq!(value.to_be_bytes(), [1, 0]);
+        value -= ONE;
+        value ^= ONE;
+        assert_eq!(value * 2, FixedBase256::from_be_bytes([1, 0xfc]));
+        assert_eq!(format!("{:X}", value), "00FE");
+        assert!(ONE < FixedBase256::from_be_bytes([1, 0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Overflow")]
+    fn test_add_overflow() {
+        let _ = FixedBase256::<2>::MAX + ONE;
+    }
+
+    #[test]
+    fn test_conversion() {
+        assert_eq!(FixedBase256::<2>::try_from(&[0u8, 0, 0, 1][..]), Ok(ONE));
+        assert!(FixedBase256::<2>::try_from(&[1u8, 0, 0][..]).is_err());
+        assert_eq!(
+            FixedBase256::<4>::try_from(&Base256::new(vec![1, 2])),
+            Ok(FixedBase256::from_be_bytes([0, 0, 1, 2]))
+        );
+        assert!(FixedBase256::<1>::try_from(&Base256::new(vec![1, 2])).is_err());
+        assert_eq!(format!("{:?}", ONE), "FixedBase256<2>(0x0001)");
+    }
+}
@@ -0,0 +1,269 @@
+use alloc::string::String;
+use crate::Base256;
+use core::fmt;
+
+/// Digits of the numeric value in `radix`, as ASCII, with no leading zeros.
+fn radix_string(value: &Base256, radix: u32, upper: bool) -> String {
+    let alphabet: &[u8; 36] = if upper {
+        b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ"
+    } else {
+        b"0123456789abcdefghijklmnopqrstuvwxyz"
+    };
+    value
+        .to_radix_digits(radix)
+        .into_iter()
+        .map(|x| alphabet[x as usize] as char)
+        .collect()
+}
+
+impl Base256 {
+    /// Lowercase digits of the numeric value in `radix`, with no leading zeros.
+    ///
+    /// Panics if `radix` is not in `2..=36`.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let id = Base256::from(1295u32);
+    /// assert_eq!(id.to_str_radix(36), "zz");
+    /// assert_eq!(Base256::from_str_radix(&id.to_str_radix(7), 7), Ok(id));
+    /// ```
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        if !(2..=36).contains(&radix) {
+            panic!("Radix must be in 2..=36, got {}", radix)
+        }
+        radix_string(self, radix, false)
+    }
+
+    /// Display adapter inserting `separator` every `every` digits, counting from the least
+    /// significant digit. Supports the same formatting traits and flags as Base256 itself.
+    ///
+    /// Panics if `every` is zero.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let id = Base256::from(0xdead_beef_u32);
+    /// assert_eq!(format!("{:x}", id.grouped(4, '_')), "dead_beef");
+    /// assert_eq!(format!("{:#x}", id.grouped(4, '_')), "0xdead_beef");
+    /// assert_eq!(format!("{}", Base256::from(1234567u32).grouped(3, ',')), "1,234,567");
+    /// ```
+    pub fn grouped(&self, every: usize, separator: char) -> Grouped<'_> {
+        if every == 0 {
+            panic!("Group size must be non-zero")
+        }
+        Grouped {
+            value: self,
+            every,
+            separator,
+        }
+    }
+}
+
+/// Bytes shown by `Debug` before the rest are elided.
+const DEBUG_PREFIX_BYTES: usize = 4;
+
+/// `Base256(0x00ab, 2 bytes)`, including leading zeros. Past the first four bytes the hex is cut
+/// short with `…`, so a 32byte hash stays readable in logs and assertion failures. The
+/// alternate `{:#?}` form shows every byte in the underlying Vec, as does
+/// [`Base256::raw_debug`].
+/// ```
+/// use byte_arithmetic::Base256;
+/// let id = Base256::new(vec![0, 0xab]);
+/// assert_eq!(format!("{:?}", id), "Base256(0x00ab, 2 bytes)");
+/// assert_eq!(format!("{:?}", id.raw_debug()), "Base256 { inner: [0, 171] }");
+/// let hash = Base256::new([0xde, 0xad, 0xbe, 0xef].repeat(8));
+/// assert_eq!(format!("{:?}", hash), "Base256(0xdeadbeef…, 32 bytes)");
+/// ```
+impl fmt::Debug for Base256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return fmt::Debug::fmt(&self.raw_debug(), f);
+        }
+        f.write_str("Base256(0x")?;
+        for x in self.inner.iter().take(DEBUG_PREFIX_BYTES) {
+            write!(f, "{:02x}", x)?;
+        }
+        if self.inner.len() > DEBUG_PREFIX_BYTES {
+            f.write_str("…")?;
+        }
+        let unit = if self.inner.len() == 1 { "byte" } else { "bytes" };
+        write!(f, ", {} {})", self.inner.len(), unit)
+    }
+}
+
+impl Base256 {
+    /// Debug adapter showing the underlying Vec, as a derived `Debug` would.
+    pub fn raw_debug(&self) -> RawDebug<'_> {
+        RawDebug { value: self }
+    }
+}
+
+/// Adapter returned by [`Base256::raw_debug`].
+#[derive(Clone, Copy)]
+pub struct RawDebug<'a> {
+    value: &'a Base256,
+}
+
+impl fmt::Debug for RawDebug<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Base256")
+            .field("inner", &self.value.inner)
+            .finish()
+    }
+}
+
+/// Adapter returned by [`Base256::grouped`].
+#[derive(Debug, Clone, Copy)]
+pub struct Grouped<'a> {
+    value: &'a Base256,
+    every: usize,
+    separator: char,
+}
+
+impl Grouped<'_> {
+    fn group(&self, radix: u32, upper: bool) -> String {
+        let digits = radix_string(self.value, radix, upper);
+        let mut res = String::with_capacity(digits.len() + digits.len() / self.every);
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % self.every == 0 {
+                res.push(self.separator);
+            }
+            res.push(digit);
+        }
+        res
+    }
+}
+
+macro_rules! impl_grouped_fmt {
+    ($($imp:ident, $radix:expr, $upper:expr, $prefix:expr);*) => {
+        $(
+            impl fmt::$imp for Grouped<'_> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.pad_integral(true, $prefix, &self.group($radix, $upper))
+                }
+            }
+        )*
+    };
+}
+
+impl_grouped_fmt!(
+    Display, 10, false, "";
+    LowerHex, 16, false, "0x";
+    UpperHex, 16, true, "0x";
+    Binary, 2, false, "0b";
+    Octal, 8, false, "0o"
+);
+
+impl fmt::Display for Base256 {
+    /// Full decimal value, i.e. `Base256::new(vec![1, 0])` displays as `256`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "", &radix_string(self, 10, false))
+    }
+}
+
+/// Integer formatting of the numeric value, honouring width, fill and the `#` prefix flag as
+/// the primitive integers do.
+/// ```
+/// use byte_arithmetic::Base256;
+/// let id = Base256::new(vec![0, 0xad, 0xff]);
+/// assert_eq!(format!("{:x}", id), "adff");
+/// assert_eq!(format!("{:#010X}", id), "0x0000ADFF");
+/// assert_eq!(format!("{:b}", Base256::new(vec![5])), "101");
+/// assert_eq!(format!("{:o}", Base256::new(vec![1, 0])), "400");
+/// ```
+impl fmt::LowerHex for Base256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0x", &radix_string(self, 16, false))
+    }
+}
+
+impl fmt::UpperHex for Base256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0x", &radix_string(self, 16, true))
+    }
+}
+
+impl fmt::Binary for Base256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0b", &radix_string(self, 2, false))
+    }
+}
+
+impl fmt::Octal for Base256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0o", &radix_string(self, 8, false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_width_hex() {
+        let hash = Base256::new(vec![0xab; 32]);
+        assert_eq!(format!("{:064x}", hash), "ab".repeat(32));
+        assert_eq!(format!("{:064x}", Base256::new(vec![1])), format!("{:064x}", 1));
+    }
+
+    #[test]
+    fn test_display_beyond_u128() {
+        let mut bytes = vec![0; 17];
+        bytes[0] = 1;
+        assert_eq!(Base256::new(bytes).to_string(), "340282366920938463463374607431768211456");
+    }
+
+    #[test]
+    fn test_to_str_radix() {
+        assert_eq!(Base256::new(vec![0, 0]).to_str_radix(36), "0");
+        assert_eq!(Base256::from(u64::MAX).to_str_radix(35), "5g24a25twkwff");
+    }
+
+    #[test]
+    fn test_fill_and_alignment() {
+        let value = Base256::new(vec![0x0a, 0xbc]);
+        assert_eq!(format!("{:*^9x}", value), "***abc***");
+        assert_eq!(format!("{:<#8x}|", value), "0xabc   |");
+        assert_eq!(format!("{:08}", value), "00002748");
+    }
+
+    #[test]
+    fn test_grouped() {
+        let hash = Base256::new(vec![0xde, 0xad, 0xbe, 0xef, 0x01]);
+        assert_eq!(format!("{:X}", hash.grouped(4, ' ')), "DE ADBE EF01");
+        assert_eq!(format!("{:b}", Base256::new(vec![5]).grouped(2, '_')), "1_01");
+        assert_eq!(format!("{:>6}", Base256::from(1000u32).grouped(3, ',')), " 1,000");
+        assert_eq!(format!("{}", Base256::empty().grouped(3, ',')), "0");
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!(format!("{:?}", Base256::empty()), "Base256(0x, 0 bytes)");
+        assert_eq!(format!("{:?}", Base256::new(vec![255])), "Base256(0xff, 1 byte)");
+        assert_eq!(
+            format!("{:#?}", Base256::new(vec![1])),
+            "Base256 {\n    inner: [\n        1,\n    ],\n}"
+        );
+        assert_eq!(format!("{:?}", Some(Base256::new(vec![1]))), "Some(Base256(0x01, 1 byte))");
+    }
+
+    #[test]
+    fn test_debug_abbreviates_long_values() {
+        let four = Base256::new(vec![0, 1, 2, 3]);
+        assert_eq!(format!("{:?}", four), "Base256(0x00010203, 4 bytes)");
+        let five = Base256::new(vec![0, 1, 2, 3, 4]);
+        assert_eq!(format!("{:?}", five), "Base256(0x00010203…, 5 bytes)");
+        let hash = Base256::new(vec![0xab; 32]);
+        assert_eq!(format!("{:?}", hash), "Base256(0xabababab…, 32 bytes)");
+        assert_eq!(format!("{:#?}", hash).matches("171").count(), 32);
+    }
+
+    #[test]
+    fn test_matches_primitive_formatting() {
+        let n = 0x00f0_1234_5678_9abc_u64;
+        let value = Base256::from(n);
+        assert_eq!(format!("{:x}", value), format!("{:x}", n));
+        assert_eq!(format!("{:>#20X}", value), format!("{:>#20X}", n));
+        assert_eq!(format!("{:#b}", value), format!("{:#b}", n));
+        assert_eq!(format!("{:o}", value), format!("{:o}", n));
+        assert_eq!(format!("{:x}", Base256::empty()), "0");
+        assert_eq!(format!("{:>25}", value), format!("{:>25}", n));
+    }
+}
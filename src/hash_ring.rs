@@ -0,0 +1,121 @@
+use alloc::collections::BTreeMap;
+use crate::Base256;
+
+/// Consistent hash ring of `byte_length` byte points, each carrying a value such as a node.
+///
+/// A key is owned by its successor, the first point clockwise from it, counting a point at the
+/// key itself. Points and keys are wrapped to `byte_length` bytes, so every ID on the ring has
+/// the same width and sorts numerically.
+/// ```
+/// use byte_arithmetic::{Base256, HashRing};
+/// let mut ring = HashRing::new(1);
+/// ring.insert(Base256::new(vec![0x40]), "a");
+/// ring.insert(Base256::new(vec![0xc0]), "b");
+/// assert_eq!(ring.owner_of(&Base256::new(vec![0x41])).map(|x| *x.1), Some("b"));
+/// assert_eq!(ring.owner_of(&Base256::new(vec![0xc1])).map(|x| *x.1), Some("a"));
+/// let replicas: Vec<&str> = ring.successors(&Base256::new(vec![0xc1]), 3).map(|x| *x.1).collect();
+/// assert_eq!(replicas, ["a", "b"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HashRing<T> {
+    byte_length: usize,
+    points: BTreeMap<Base256, T>,
+}
+
+impl<T> HashRing<T> {
+    pub fn new(byte_length: usize) -> Self {
+        HashRing {
+            byte_length,
+            points: BTreeMap::new(),
+        }
+    }
+
+    pub fn byte_length(&self) -> usize {
+        self.byte_length
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Place `value` at `point`, returning the value it replaces.
+    pub fn insert(&mut self, point: Base256, value: T) -> Option<T> {
+        let point = self.wrap(&point);
+        self.points.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: &Base256) -> Option<T> {
+        self.points.remove(&self.wrap(point))
+    }
+
+    /// The point owning `key` and its value, or `None` if the ring is empty.
+    pub fn owner_of(&self, key: &Base256) -> Option<(&Base256, &T)> {
+        self.successors(key, 1).next()
+    }
+
+    /// Up to `n` distinct points clockwise from `key`, starting with its owner, i.e. the nodes
+    /// holding its replicas.
+    pub fn successors(&self, key: &Base256, n: usize) -> impl Iterator<Item = (&Base256, &T)> {
+        let key = self.wrap(key);
+        self.points
+            .range(key.clone()..)
+            .chain(self.points.range(..key))
+            .take(n)
+    }
+
+    /// Points and values in clockwise order from zero.
+    pub fn iter(&self) -> impl Iterator<Item = (&Base256, &T)> {
+        self.points.iter()
+    }
+
+    fn wrap(&self, id: &Base256) -> Base256 {
+        Base256::new(id.wrapped_bytes(self.byte_length))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn ring(points: &[u16]) -> HashRing<u16> {
+        let mut ring = HashRing::new(2);
+        for point in points {
+            ring.insert(Base256::from(*point), *point);
+        }
+        ring
+    }
+
+    #[test]
+    fn test_owner_matches_linear_scan() {
+        let points = [0x0100, 0x7fff, 0x8000, 0xff00];
+        let ring = ring(&points);
+        for key in (0..=0xffffu32).step_by(0x7f).map(|x| x as u16) {
+            let expected = points.iter().copied().find(|x| *x >= key).unwrap_or(points[0]);
+            assert_eq!(ring.owner_of(&Base256::from(key)).map(|x| *x.1), Some(expected));
+        }
+        assert_eq!(ring.owner_of(&Base256::from(0x8000u16)).map(|x| *x.1), Some(0x8000));
+    }
+
+    #[test]
+    fn test_successors_wrap_without_repeats() {
+        let ring = ring(&[10, 20, 30]);
+        let replicas: Vec<u16> = ring.successors(&Base256::from(25u16), 5).map(|x| *x.1).collect();
+        assert_eq!(replicas, vec![30, 10, 20]);
+        assert_eq!(HashRing::<u8>::new(2).owner_of(&Base256::new(vec![1])), None);
+    }
+
+    #[test]
+    fn test_points_wrap_to_width() {
+        let mut ring = ring(&[5]);
+        assert_eq!(ring.insert(Base256::new(vec![1, 0, 5]), 6), Some(5));
+        assert_eq!(ring.iter().next(), Some((&Base256::new(vec![0, 5]), &6)));
+        assert_eq!(ring.remove(&Base256::new(vec![5])), Some(6));
+        assert!(ring.is_empty());
+    }
+}
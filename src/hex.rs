@@ -0,0 +1,99 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::{Base256, ParseError};
+
+const LOWER: &[u8; 16] = b"0123456789abcdef";
+const UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+fn encode(bytes: &[u8], alphabet: &[u8; 16]) -> String {
+    let mut res = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        res.push(alphabet[(byte >> 4) as usize] as char);
+        res.push(alphabet[(byte & 0xf) as usize] as char);
+    }
+    res
+}
+
+/// Strip an optional `0x`/`0X` prefix, returning the remainder and its offset into `s`.
+pub(crate) fn strip_hex_prefix(s: &str) -> (&str, usize) {
+    match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(rest) => (rest, 2),
+        None => (s, 0),
+    }
+}
+
+impl Base256 {
+    /// Parse hexadecimal, with an optional `0x` prefix. Odd-length input is read as if it had a
+    /// leading zero.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// assert_eq!(Base256::from_hex("0xdeadBEEF"), Ok(Base256::new(vec![0xde, 0xad, 0xbe, 0xef])));
+    /// assert_eq!(Base256::from_hex("fff"), Ok(Base256::new(vec![0x0f, 0xff])));
+    /// ```
+    pub fn from_hex(s: &str) -> Result<Self, ParseError> {
+        let (digits, offset) = strip_hex_prefix(s);
+        if digits.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let nibbles = digits
+            .bytes()
+            .enumerate()
+            .map(|(index, x)| match x {
+                b'0'..=b'9' => Ok(x - b'0'),
+                b'a'..=b'f' => Ok(x - b'a' + 10),
+                b'A'..=b'F' => Ok(x - b'A' + 10),
+                _ => Err(ParseError::InvalidDigit {
+                    index: index + offset,
+                }),
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+        let mut res = Vec::with_capacity(nibbles.len().div_ceil(2));
+        let (head, tail) = nibbles.split_at(nibbles.len() % 2);
+        res.extend(head.iter().copied());
+        res.extend(tail.chunks(2).map(|pair| (pair[0] << 4) | pair[1]));
+        Ok(Base256::new(res))
+    }
+
+    /// Lowercase hexadecimal of every byte, including leading zeros, without a prefix.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// assert_eq!(Base256::new(vec![0, 0xab]).to_hex(), "00ab");
+    /// assert_eq!(Base256::new(vec![0, 0xab]).to_hex_upper(), "00AB");
+    /// ```
+    pub fn to_hex(&self) -> String {
+        encode(&self.inner, LOWER)
+    }
+
+    /// Uppercase hexadecimal of every byte, including leading zeros, without a prefix.
+    pub fn to_hex_upper(&self) -> String {
+        encode(&self.inner, UPPER)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_round_trip() {
+        let value = Base256::new(vec![0, 1, 0x7f, 0x80, 0xff]);
+        assert_eq!(value.to_hex(), "00017f80ff");
+        assert_eq!(Base256::from_hex(&value.to_hex()), Ok(value.clone()));
+        assert_eq!(Base256::from_hex(&value.to_hex_upper()), Ok(value));
+        assert_eq!(Base256::empty().to_hex(), "");
+    }
+
+    #[test]
+    fn test_hex_errors() {
+        assert_eq!(Base256::from_hex(""), Err(ParseError::Empty));
+        assert_eq!(Base256::from_hex("0x"), Err(ParseError::Empty));
+        assert_eq!(Base256::from_hex("0x12g4"), Err(ParseError::InvalidDigit { index: 4 }));
+        assert_eq!(Base256::from_hex(" 12"), Err(ParseError::InvalidDigit { index: 0 }));
+    }
+
+    #[test]
+    fn test_hex_odd_length() {
+        assert_eq!(Base256::from_hex("0X1"), Ok(Base256::new(vec![1])));
+        assert_eq!(Base256::from_hex("abc"), Ok(Base256::new(vec![0x0a, 0xbc])));
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{Base256, FixedBase256};
+use ::arbitrary::{size_hint, Arbitrary, Result, Unstructured};
+
+/// Longest value generated, enough for 512bit operands while keeping fuzz inputs focused on
+/// carries and borrows rather than sheer length.
+const MAX_ARBITRARY_LEN: usize = 64;
+
+impl<'a> Arbitrary<'a> for Base256 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.arbitrary_len::<u8>()?.min(MAX_ARBITRARY_LEN);
+        Ok(Base256::from(u.bytes(len)?))
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        let rest = u.take_rest();
+        Ok(Base256::from(&rest[..rest.len().min(MAX_ARBITRARY_LEN)]))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(<usize as Arbitrary>::size_hint(depth), (0, Some(MAX_ARBITRARY_LEN)))
+    }
+}
+
+impl<'a, const N: usize> Arbitrary<'a> for FixedBase256<N> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(FixedBase256::from_be_bytes(u.arbitrary()?))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (N, Some(N))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arbitrary_bounds() {
+        let data = [0xffu8; 1024];
+        let value = Base256::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert!(value.len() <= MAX_ARBITRARY_LEN);
+        let rest = Base256::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+        assert_eq!(rest.len(), MAX_ARBITRARY_LEN);
+        let fixed = FixedBase256::<4>::arbitrary(&mut Unstructured::new(&[1, 2, 3, 4])).unwrap();
+        assert_eq!(fixed.to_be_bytes(), [1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::Base256;
+use ::bitvec::prelude::{BitSlice, BitVec, Msb0};
+
+/// Bits are most significant first. Bit sequences that aren't a whole number of bytes are read
+/// as a number, so are left-padded with zero bits.
+/// ```
+/// use bitvec::prelude::*;
+/// use byte_arithmetic::Base256;
+/// let bits = bitvec![u8, Msb0; 1, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+/// assert_eq!(Base256::from(bits.as_bitslice()), Base256::new(vec![2, 1]));
+/// assert_eq!(BitVec::from(&Base256::new(vec![128])), bitvec![u8, Msb0; 1, 0, 0, 0, 0, 0, 0, 0]);
+/// ```
+impl From<&BitSlice<u8, Msb0>> for Base256 {
+    fn from(bits: &BitSlice<u8, Msb0>) -> Self {
+        let mut padded: BitVec<u8, Msb0> = BitVec::repeat(false, (8 - bits.len() % 8) % 8);
+        padded.extend_from_bitslice(bits);
+        Base256::new(padded.into_vec())
+    }
+}
+
+impl From<BitVec<u8, Msb0>> for Base256 {
+    fn from(bits: BitVec<u8, Msb0>) -> Self {
+        Base256::from(bits.as_bitslice())
+    }
+}
+
+impl From<&Base256> for BitVec<u8, Msb0> {
+    fn from(value: &Base256) -> Self {
+        BitVec::from_slice(value)
+    }
+}
+
+impl From<Base256> for BitVec<u8, Msb0> {
+    fn from(value: Base256) -> Self {
+        BitVec::from_vec(value.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::bitvec::bitvec;
+
+    #[test]
+    fn test_bitvec_round_trip() {
+        let value = Base256::new(vec![0xde, 0xad]);
+        let bits = BitVec::from(value.clone());
+        assert_eq!(bits.len(), 16);
+        assert!(bits[0] && !bits[2]);
+        assert_eq!(Base256::from(bits), value);
+    }
+
+    #[test]
+    fn test_partial_byte() {
+        assert_eq!(Base256::from(bitvec![u8, Msb0; 1, 1]), Base256::new(vec![3]));
+        assert_eq!(Base256::from(BitVec::<u8, Msb0>::new()), Base256::empty());
+        let bits = BitVec::from(Base256::new(vec![0b1000_0001, 0]));
+        assert_eq!(Base256::from(&bits[1..]), Base256::new(vec![1, 0]));
+    }
+}
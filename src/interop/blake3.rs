@@ -0,0 +1,42 @@
+use crate::{Base256, OverflowError};
+use ::blake3::Hash;
+
+impl From<Hash> for Base256 {
+    fn from(hash: Hash) -> Self {
+        Base256::from(*hash.as_bytes())
+    }
+}
+
+impl TryFrom<&Base256> for Hash {
+    type Error = OverflowError;
+
+    fn try_from(value: &Base256) -> Result<Self, Self::Error> {
+        value.to_array_for("blake3::Hash").map(Hash::from)
+    }
+}
+
+impl Base256 {
+    /// BLAKE3 hash of `data`.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let id = Base256::blake3(b"node-a");
+    /// assert_eq!(id, blake3::hash(b"node-a").as_bytes().as_slice());
+    /// ```
+    pub fn blake3(data: &[u8]) -> Self {
+        ::blake3::hash(data).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blake3_round_trip() {
+        let hash = ::blake3::hash(b"abc");
+        let value = Base256::from(hash);
+        assert_eq!(value.len(), 32);
+        assert_eq!(Hash::try_from(&value), Ok(hash));
+        assert!(Hash::try_from(&Base256::new(vec![1; 33])).is_err());
+    }
+}
@@ -0,0 +1,67 @@
+use alloc::vec::Vec;
+use crate::Base256;
+use ::bytes::{Buf, BufMut, Bytes};
+
+impl From<Bytes> for Base256 {
+    fn from(bytes: Bytes) -> Self {
+        Base256::new(Vec::from(bytes))
+    }
+}
+
+impl From<Base256> for Bytes {
+    fn from(value: Base256) -> Self {
+        Bytes::from(Vec::from(value))
+    }
+}
+
+impl Base256 {
+    /// Convert into `Bytes` without copying the underlying buffer.
+    pub fn into_bytes(self) -> Bytes {
+        self.into()
+    }
+
+    /// Write the big-endian bytes of the value to `buf`.
+    ///
+    /// Panics if `buf` does not have enough remaining capacity, as with `BufMut::put_slice`.
+    pub fn put_to(&self, buf: &mut impl BufMut) {
+        buf.put_slice(self);
+    }
+
+    /// Read `len` big-endian bytes from `buf`, or `None` if fewer than `len` remain.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let mut wire = Vec::new();
+    /// Base256::new(vec![1, 2]).put_to(&mut wire);
+    /// Base256::new(vec![3]).put_to(&mut wire);
+    /// let mut buf = wire.as_slice();
+    /// assert_eq!(Base256::from_buf(&mut buf, 2), Some(Base256::new(vec![1, 2])));
+    /// assert_eq!(Base256::from_buf(&mut buf, 2), None);
+    /// ```
+    pub fn from_buf(buf: &mut impl Buf, len: usize) -> Option<Self> {
+        if buf.remaining() < len {
+            return None;
+        }
+        Some(buf.copy_to_bytes(len).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let value = Base256::new(vec![9, 8, 7]);
+        let bytes = value.clone().into_bytes();
+        assert_eq!(bytes.as_ref(), &[9, 8, 7]);
+        assert_eq!(Base256::from(bytes), value);
+    }
+
+    #[test]
+    fn test_from_buf_leaves_short_input() {
+        let mut buf = Bytes::from_static(&[1, 2, 3]);
+        assert_eq!(Base256::from_buf(&mut buf, 4), None);
+        assert_eq!(buf.remaining(), 3);
+        assert_eq!(Base256::from_buf(&mut buf, 3), Some(Base256::new(vec![1, 2, 3])));
+    }
+}
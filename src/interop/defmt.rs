@@ -0,0 +1,59 @@
+use crate::{Base256, FixedBase256};
+use ::defmt::{Format, Formatter};
+
+/// The bytes are sent as they are and rendered on the host, so nothing is formatted on the
+/// device. defmt applies a display hint on a slice to each element, so `0xdead` is shown as
+/// `Base256([de, ad])`.
+impl Format for Base256 {
+    fn format(&self, f: Formatter) {
+        ::defmt::write!(f, "Base256({=[u8]:02x})", self.inner.as_slice())
+    }
+}
+
+impl<const N: usize> Format for FixedBase256<N> {
+    fn format(&self, f: Formatter) {
+        ::defmt::write!(f, "FixedBase256({=[u8]:02x})", self.as_bytes().as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use defmt_parser::{DisplayHint, Fragment, ParserMode, Type};
+
+    #[test]
+    fn test_format_strings_hint_each_byte() {
+        let formats: Vec<&str> = include_str!("defmt.rs")
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("::defmt::write!(f, \""))
+            .filter_map(|line| line.split('"').next())
+            .collect();
+        assert_eq!(formats, ["Base256({=[u8]:02x})", "FixedBase256({=[u8]:02x})"]);
+        for format in formats {
+            let fragments = defmt_parser::parse(format, ParserMode::Strict).unwrap();
+            let hex = DisplayHint::Hexadecimal { alternate: false, uppercase: false, zero_pad: 2 };
+            assert!(fragments.iter().any(|x| matches!(
+                x,
+                Fragment::Parameter(p) if p.ty == Type::U8Slice && p.hint.as_ref() == Some(&hex)
+            )));
+            for fragment in fragments {
+                if let Fragment::Literal(literal) = fragment {
+                    assert!(!literal.contains("0x"), "{}", format);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bytes_are_encoded_as_a_slice() {
+        let index = ::defmt::export::fetch_string_index();
+        Base256::new(vec![0xde, 0xad]).format(::defmt::export::make_formatter());
+        // The interned format string, then the slice as a `u32` length and its bytes.
+        let mut expected = index.to_le_bytes().to_vec();
+        expected.extend(2u32.to_le_bytes());
+        expected.extend([0xde, 0xad]);
+        assert_eq!(::defmt::export::fetch_bytes(), expected);
+    }
+}
@@ -0,0 +1,42 @@
+use crate::Base256;
+use ::digest::generic_array::{ArrayLength, GenericArray};
+use ::digest::Digest;
+
+impl<N: ArrayLength<u8>> From<GenericArray<u8, N>> for Base256 {
+    fn from(array: GenericArray<u8, N>) -> Self {
+        Base256::new(array.to_vec())
+    }
+}
+
+impl Base256 {
+    /// Hash `data` with `D`, using the digest output as the value.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// use sha2::{Digest, Sha256};
+    /// let id = Base256::from_digest::<Sha256>(b"node-a");
+    /// assert_eq!(id, Sha256::digest(b"node-a").as_slice());
+    /// ```
+    pub fn from_digest<D: Digest>(data: &[u8]) -> Self {
+        D::digest(data).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::{Sha256, Sha512};
+
+    #[test]
+    fn test_from_digest_width() {
+        assert_eq!(Base256::from_digest::<Sha256>(b"").len(), 32);
+        assert_eq!(Base256::from_digest::<Sha512>(b"").len(), 64);
+    }
+
+    #[test]
+    fn test_from_generic_array() {
+        let mut hasher = Sha256::new();
+        hasher.update(b"abc");
+        let value = Base256::from(hasher.finalize());
+        assert_eq!(value[..4], [0xba, 0x78, 0x16, 0xbf]);
+    }
+}
@@ -0,0 +1,37 @@
+//! Conversions to and from types of other crates, each behind a feature of the same name.
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "bitvec")]
+mod bitvec;
+#[cfg(feature = "blake3")]
+mod blake3;
+#[cfg(feature = "bytes")]
+mod bytes;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "digest")]
+mod digest;
+#[cfg(feature = "multihash")]
+mod multihash;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "secrecy")]
+mod secrecy;
+#[cfg(feature = "subtle")]
+mod subtle;
+#[cfg(feature = "tokio")]
+mod tokio;
+#[cfg(feature = "uuid")]
+mod uuid;
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+#[cfg(feature = "rand")]
+pub use self::rand::UniformBase256;
+#[cfg(feature = "secrecy")]
+pub use self::secrecy::{SecretArithmetic, SecretBase256};
+#[cfg(feature = "tokio")]
+pub use self::tokio::Base256Codec;
@@ -0,0 +1,53 @@
+use crate::Base256;
+use ::multihash::{Error, Multihash};
+
+/// A multihash converts to its digest bytes, dropping the code and length prefix. Keep
+/// `Multihash::code` alongside the value to convert back with `to_multihash`.
+/// ```
+/// use byte_arithmetic::Base256;
+/// use multihash::Multihash;
+/// let a = Multihash::<64>::wrap(0x12, &[0b1010, 0b0001]).unwrap();
+/// let b = Multihash::<64>::wrap(0x12, &[0b0110, 0b0001]).unwrap();
+/// let distance = Base256::from(&a) ^ Base256::from(&b);
+/// assert_eq!(distance, Base256::new(vec![0b1100, 0]));
+/// assert_eq!(distance.to_multihash::<64>(a.code()).unwrap().digest(), &[0b1100, 0]);
+/// ```
+impl<const S: usize> From<&Multihash<S>> for Base256 {
+    fn from(multihash: &Multihash<S>) -> Self {
+        Base256::new(multihash.digest().to_vec())
+    }
+}
+
+impl<const S: usize> From<Multihash<S>> for Base256 {
+    fn from(multihash: Multihash<S>) -> Self {
+        Base256::from(&multihash)
+    }
+}
+
+impl Base256 {
+    /// Wrap the bytes of the value as the digest of a multihash with the given code.
+    ///
+    /// Errors if the value is longer than `S` bytes.
+    pub fn to_multihash<const S: usize>(&self, code: u64) -> Result<Multihash<S>, Error> {
+        Multihash::wrap(code, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multihash_round_trip() {
+        let multihash = Multihash::<32>::wrap(0x1e, &[0, 1, 2]).unwrap();
+        let value = Base256::from(multihash);
+        assert_eq!(value, Base256::new(vec![0, 1, 2]));
+        assert_eq!(value.to_multihash::<32>(0x1e).unwrap(), multihash);
+        assert_eq!(multihash.size(), 3);
+    }
+
+    #[test]
+    fn test_to_multihash_too_long() {
+        assert!(Base256::new(vec![1; 33]).to_multihash::<32>(0x12).is_err());
+    }
+}
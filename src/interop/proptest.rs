@@ -0,0 +1,67 @@
+//! Proptest strategies for Base256 operands.
+//!
+//! `any::<Base256>()` generates values of up to 64 bytes; the functions here bound the length
+//! or pair operands up.
+//! ```
+//! use byte_arithmetic::proptest::same_length_pair;
+//! use proptest::prelude::*;
+//! proptest!(|((a, b) in same_length_pair(32))| {
+//!     prop_assert_eq!(a.len(), b.len());
+//!     prop_assert_eq!((a.clone() ^ b.clone()) ^ b, a);
+//! });
+//! ```
+
+use crate::{Base256, FixedBase256};
+use ::proptest::arbitrary::{any, Arbitrary};
+use ::proptest::collection::vec;
+use ::proptest::strategy::{BoxedStrategy, Strategy};
+
+/// Values of `0..=max_len` bytes, including leading zeros.
+pub fn any_base256(max_len: usize) -> impl Strategy<Value = Base256> {
+    vec(any::<u8>(), 0..=max_len).prop_map(Base256::new)
+}
+
+/// Two values of the same length, up to `max_len` bytes.
+pub fn same_length_pair(max_len: usize) -> impl Strategy<Value = (Base256, Base256)> {
+    (0..=max_len)
+        .prop_flat_map(|len| (vec(any::<u8>(), len), vec(any::<u8>(), len)))
+        .prop_map(|(a, b)| (Base256::new(a), Base256::new(b)))
+}
+
+impl Arbitrary for Base256 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any_base256(64).boxed()
+    }
+}
+
+impl<const N: usize> Arbitrary for FixedBase256<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        vec(any::<u8>(), N)
+            .prop_map(|bytes| FixedBase256::from_be_bytes(bytes.try_into().unwrap()))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_any_base256_bounds(value in any_base256(5)) {
+            prop_assert!(value.len() <= 5);
+        }
+
+        #[test]
+        fn test_fixed_round_trip(value in any::<FixedBase256<3>>()) {
+            prop_assert_eq!(FixedBase256::<3>::try_from(&Base256::from(value)), Ok(value));
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use alloc::vec;
+use crate::{cmp_numeric, Base256, FixedBase256};
+use ::rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use ::rand::distributions::{Distribution, Standard};
+use ::rand::Rng;
+use core::cmp::Ordering;
+use core::ops::RangeInclusive;
+
+impl Base256 {
+    /// Uniformly random value of exactly `byte_length` bytes, i.e. a fresh node ID.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let id = Base256::random(32, &mut rand::thread_rng());
+    /// assert_eq!(id.len(), 32);
+    /// ```
+    pub fn random<R: Rng + ?Sized>(byte_length: usize, rng: &mut R) -> Self {
+        let mut res = vec![0; byte_length];
+        rng.fill_bytes(&mut res);
+        Base256::new(res)
+    }
+
+    /// Uniformly random value in `0..modulus`, at the width of `modulus`.
+    ///
+    /// Candidates are drawn with the bits above the modulus' highest set bit cleared and rejected
+    /// until one is below the modulus, so no value is favoured; on average fewer than two draws
+    /// are needed. Panics if `modulus` is zero.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let order = Base256::new(vec![0x01, 0x00, 0x01]);
+    /// let nonce = Base256::random_below(&order, &mut rand::thread_rng());
+    /// assert!(nonce < order);
+    /// ```
+    pub fn random_below<R: Rng + ?Sized>(modulus: &Base256, rng: &mut R) -> Self {
+        let significant = modulus.significant_bytes();
+        let top = match significant.first() {
+            Some(top) => *top,
+            None => panic!("Modulus must be non-zero"),
+        };
+        let mask = u8::MAX >> top.leading_zeros();
+        let mut candidate = vec![0; significant.len()];
+        loop {
+            rng.fill_bytes(&mut candidate);
+            candidate[0] &= mask;
+            if cmp_numeric(&candidate, significant) == Ordering::Less {
+                return Base256::new(candidate).with_width(modulus.len());
+            }
+        }
+    }
+
+    /// Uniformly random value in `start..=end` on the ring of values as wide as the wider bound.
+    ///
+    /// If `start` is numerically greater than `end` the range wraps, covering `start` up to the
+    /// largest value of that width and then zero up to `end`, as an interval of a Kademlia or
+    /// consistent hashing ring does.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let wrapped = Base256::new(vec![0xfe])..=Base256::new(vec![0x01]);
+    /// let value = Base256::random_in_range(wrapped, &mut rand::thread_rng());
+    /// assert!([0xfe, 0xff, 0x00, 0x01].contains(&value[0]));
+    /// ```
+    pub fn random_in_range<R: Rng + ?Sized>(range: RangeInclusive<Base256>, rng: &mut R) -> Self {
+        let (start, end) = range.into_inner();
+        let width = core::cmp::max(start.len(), end.len());
+        let mut ring = vec![0; width + 1];
+        ring[0] = 1;
+        let span = ((&end + Base256::new(ring)) - &start).wrap_to(width) + Base256::new(vec![1]);
+        (start + Base256::random_below(&span, rng))
+            .wrap_to(width)
+            .with_width(width)
+    }
+
+    /// Random value of `total_bytes` bytes whose highest `prefix_bits` bits are the highest
+    /// `prefix_bits` bits of `prefix`, as its bytes are stored; the remaining bits are random.
+    ///
+    /// This gives a lookup target in a given routing table bucket, i.e. one sharing exactly a
+    /// prefix with the local node ID. Panics if `prefix_bits` exceeds the bits of `prefix` or of
+    /// `total_bytes`.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let node = Base256::new(vec![0b1011_0000, 0xff]);
+    /// let target = Base256::random_with_prefix(&node, 4, 2, &mut rand::thread_rng());
+    /// assert_eq!(target[0] >> 4, 0b1011);
+    /// ```
+    pub fn random_with_prefix<R: Rng + ?Sized>(
+        prefix: &Base256,
+        prefix_bits: usize,
+        total_bytes: usize,
+        rng: &mut R,
+    ) -> Self {
+        if prefix_bits > prefix.len() * 8 || prefix_bits > total_bytes * 8 {
+            panic!("Prefix longer than the value")
+        }
+        let mut res = Base256::random(total_bytes, rng);
+        let (whole, bits) = (prefix_bits / 8, prefix_bits % 8);
+        res.inner[..whole].copy_from_slice(&prefix[..whole]);
+        if bits > 0 {
+            let mask = u8::MAX << (8 - bits);
+            res.inner[whole] = (prefix[whole] & mask) | (res.inner[whole] & !mask);
+        }
+        res
+    }
+}
+
+/// Sampler behind `rng.gen_range(low..high)` and `Uniform` for Base256 bounds.
+///
+/// Samples are uniform over the numeric range and returned at the width of the wider bound.
+/// `gen_range` checks for an empty range with the derived, lexicographic `PartialOrd`, so give
+/// both bounds the same width.
+/// ```
+/// use byte_arithmetic::Base256;
+/// use rand::Rng;
+/// let low = Base256::new(vec![0, 0x10]);
+/// let high = Base256::new(vec![0, 0x20]);
+/// let id = rand::thread_rng().gen_range(low.clone()..high.clone());
+/// assert!(low <= id && id < high);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UniformBase256 {
+    low: Base256,
+    span: Base256,
+    width: usize,
+}
+
+impl SampleUniform for Base256 {
+    type Sampler = UniformBase256;
+}
+
+impl UniformSampler for UniformBase256 {
+    type X = Base256;
+
+    /// Panics if `low >= high` numerically.
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        if cmp_numeric(low, high) != Ordering::Less {
+            panic!("Uniform::new called with `low >= high`")
+        }
+        UniformBase256 {
+            low: low.clone(),
+            span: high - low,
+            width: core::cmp::max(low.len(), high.len()),
+        }
+    }
+
+    /// Panics if `low > high` numerically.
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        if cmp_numeric(low, high) == Ordering::Greater {
+            panic!("Uniform::new_inclusive called with `low > high`")
+        }
+        UniformBase256 {
+            low: low.clone(),
+            span: high - low + Base256::new(vec![1]),
+            width: core::cmp::max(low.len(), high.len()),
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        (&self.low + Base256::random_below(&self.span, rng)).with_width(self.width)
+    }
+}
+
+/// Uniformly random `N` byte values from `rng.gen()`.
+impl<const N: usize> Distribution<FixedBase256<N>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FixedBase256<N> {
+        let mut bytes = [0; N];
+        rng.fill_bytes(&mut bytes);
+        FixedBase256::from_be_bytes(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_random_is_seeded() {
+        let a = Base256::random(20, &mut ChaCha8Rng::seed_from_u64(7));
+        let b = Base256::random(20, &mut ChaCha8Rng::seed_from_u64(7));
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 20);
+        assert_eq!(Base256::random(0, &mut ChaCha8Rng::seed_from_u64(7)), Base256::empty());
+    }
+
+    #[test]
+    fn test_random_below_is_uniform() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let modulus = Base256::new(vec![0, 0, 5]);
+        let mut counts = [0u32; 5];
+        for _ in 0..5000 {
+            let value = Base256::random_below(&modulus, &mut rng);
+            assert_eq!(value.len(), 3);
+            counts[u64::try_from(&value).unwrap() as usize] += 1;
+        }
+        assert!(counts.iter().all(|x| (900..1100).contains(x)), "{:?}", counts);
+    }
+
+    #[test]
+    fn test_random_below_wide_modulus() {
+        let mut rng = ChaCha8Rng::seed_from_u64(2);
+        let modulus = Base256::new(vec![1, 0, 0, 0, 0, 0, 0, 0, 0]);
+        for _ in 0..100 {
+            let value = Base256::random_below(&modulus, &mut rng);
+            assert!(cmp_numeric(&value, &modulus) == Ordering::Less);
+        }
+        assert_eq!(
+            Base256::random_below(&Base256::new(vec![1]), &mut rng),
+            Base256::new(vec![0])
+        );
+    }
+
+    #[test]
+    fn test_gen_range() {
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        let (low, high) = (Base256::new(vec![0, 250]), Base256::new(vec![1, 4]));
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..1000 {
+            let value = rng.gen_range(low.clone()..high.clone());
+            assert!(low <= value && value < high);
+            seen.insert(value);
+        }
+        assert_eq!(seen.len(), 10);
+        let single = UniformBase256::new_inclusive(Base256::new(vec![7]), Base256::new(vec![0, 7]));
+        assert_eq!(single.sample(&mut rng), Base256::new(vec![0, 7]));
+    }
+
+    #[test]
+    fn test_random_in_range_wraps() {
+        let mut rng = ChaCha8Rng::seed_from_u64(5);
+        let mut seen = std::collections::BTreeSet::new();
+        for _ in 0..500 {
+            let range = Base256::new(vec![0xff, 0xfd])..=Base256::new(vec![0x01]);
+            seen.insert(Base256::random_in_range(range, &mut rng));
+        }
+        let expected = [[0xff, 0xfd], [0xff, 0xfe], [0xff, 0xff], [0, 0], [0, 1]];
+        assert_eq!(seen, expected.iter().map(|x| Base256::from(*x)).collect());
+        for _ in 0..100 {
+            let range = Base256::new(vec![3])..=Base256::new(vec![5]);
+            let value = Base256::random_in_range(range, &mut rng);
+            assert!((3..=5).contains(&value[0]));
+        }
+        let whole = Base256::new(vec![1])..=Base256::new(vec![0]);
+        assert_eq!(Base256::random_in_range(whole, &mut rng).len(), 1);
+    }
+
+    #[test]
+    fn test_random_with_prefix() {
+        let mut rng = ChaCha8Rng::seed_from_u64(6);
+        let prefix = Base256::new(vec![0xab, 0b1100_0000]);
+        let mut low_bits = 0u8;
+        for _ in 0..200 {
+            let value = Base256::random_with_prefix(&prefix, 10, 3, &mut rng);
+            assert_eq!(value.len(), 3);
+            assert_eq!(value[0], 0xab);
+            assert_eq!(value[1] >> 6, 0b11);
+            low_bits |= value[1] & 0b0011_1111;
+        }
+        assert_eq!(low_bits, 0b0011_1111);
+        let exact = Base256::random_with_prefix(&prefix, 16, 2, &mut rng);
+        assert_eq!(exact, prefix);
+    }
+
+    #[test]
+    #[should_panic(expected = "Prefix longer than the value")]
+    fn test_random_with_prefix_too_long() {
+        let prefix = Base256::new(vec![0xab, 0xcd]);
+        Base256::random_with_prefix(&prefix, 9, 1, &mut ChaCha8Rng::seed_from_u64(0));
+    }
+
+    #[test]
+    fn test_standard_fixed() {
+        let fixed: FixedBase256<16> = ChaCha8Rng::seed_from_u64(4).gen();
+        let bytes = Base256::random(16, &mut ChaCha8Rng::seed_from_u64(4));
+        assert_eq!(Base256::from(fixed), bytes);
+    }
+
+    #[test]
+    #[should_panic(expected = "Modulus must be non-zero")]
+    fn test_random_below_zero() {
+        Base256::random_below(&Base256::new(vec![0, 0]), &mut ChaCha8Rng::seed_from_u64(0));
+    }
+}
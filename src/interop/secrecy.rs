@@ -0,0 +1,58 @@
+use crate::Base256;
+use ::secrecy::{CloneableSecret, DebugSecret, ExposeSecret, Secret};
+
+/// Base256 that is zeroed on drop and redacted in `Debug` output.
+pub type SecretBase256 = Secret<Base256>;
+
+impl CloneableSecret for Base256 {}
+
+impl DebugSecret for Base256 {}
+
+/// Arithmetic on secrets that keeps every operand and result wrapped, so that computing with a
+/// secret scalar never requires exposing it at the call site. Temporaries are zeroed on drop.
+/// ```
+/// use byte_arithmetic::{Base256, SecretArithmetic, SecretBase256};
+/// use secrecy::ExposeSecret;
+/// let key = SecretBase256::new(Base256::new(vec![0xff, 0x01]));
+/// let tweak = SecretBase256::new(Base256::new(vec![0x00, 0xff]));
+/// let tweaked = key.wrapped_add(&tweak, 2);
+/// assert_eq!(format!("{:?}", tweaked), "Secret([REDACTED byte_arithmetic::Base256])");
+/// assert_eq!(tweaked.expose_secret(), &Base256::new(vec![0, 0]));
+/// ```
+pub trait SecretArithmetic: Sized {
+    fn wrapped_add(&self, rhs: &Self, byte_length: usize) -> Self;
+    fn xor(&self, rhs: &Self) -> Self;
+    fn wrapped_scalar_multiply(&self, value: u8, byte_length: usize) -> Self;
+}
+
+impl SecretArithmetic for SecretBase256 {
+    fn wrapped_add(&self, rhs: &Self, byte_length: usize) -> Self {
+        let (a, b) = (self.expose_secret(), rhs.expose_secret());
+        Secret::new((a + b).wrap_to(byte_length))
+    }
+
+    fn xor(&self, rhs: &Self) -> Self {
+        Secret::new(self.expose_secret() ^ rhs.expose_secret())
+    }
+
+    fn wrapped_scalar_multiply(&self, value: u8, byte_length: usize) -> Self {
+        Secret::new(self.expose_secret().mul_u64(value as u64).wrap_to(byte_length))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_arithmetic() {
+        let a = SecretBase256::new(Base256::new(vec![0x80, 0x01]));
+        let b = SecretBase256::new(Base256::new(vec![0x01, 0x01]));
+        assert_eq!(a.xor(&b).expose_secret(), &Base256::new(vec![0x81, 0x00]));
+        assert_eq!(
+            a.wrapped_scalar_multiply(3, 2).expose_secret(),
+            &Base256::new(vec![0x80, 0x03])
+        );
+        assert_eq!(a.clone().expose_secret(), a.expose_secret());
+    }
+}
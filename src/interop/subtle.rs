@@ -0,0 +1,186 @@
+use alloc::vec;
+use crate::{Base256, FixedBase256};
+use ::subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+};
+
+/// The byte of `bytes` at position `i` of a `width` byte big-endian view, which drops the bytes
+/// above `width` and reads absent ones as zero. Branches only on lengths and `i`.
+fn byte_at(bytes: &[u8], width: usize, i: usize) -> u8 {
+    let len = bytes.len();
+    if width >= len {
+        if i < width - len { 0 } else { bytes[i - (width - len)] }
+    } else {
+        bytes[i + (len - width)]
+    }
+}
+
+/// Constant-time `a > b` over big-endian bytes, reading absent leading bytes as zero. Time
+/// depends only on the lengths.
+fn ct_gt_bytes(a: &[u8], b: &[u8]) -> Choice {
+    let width = core::cmp::max(a.len(), b.len());
+    let mut greater = Choice::from(0);
+    let mut decided = Choice::from(0);
+    for i in 0..width {
+        let (x, y) = (byte_at(a, width, i), byte_at(b, width, i));
+        greater |= !decided & x.ct_gt(&y);
+        decided |= !x.ct_eq(&y);
+    }
+    greater
+}
+
+impl Base256 {
+    /// `(self + rhs) mod 2^(8 * byte_length)` as exactly `byte_length` bytes, in time that depends
+    /// only on the lengths: no branches or early exits on the values.
+    ///
+    /// Operands wider than `byte_length` wrap first, as with `wrapped_add`.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let a = Base256::new(vec![0xff, 0xff]);
+    /// assert_eq!(a.ct_add(&Base256::new(vec![1]), 2), Base256::new(vec![0, 0]));
+    /// assert_eq!(Base256::new(vec![1]).ct_sub(&Base256::new(vec![2]), 2), a);
+    /// ```
+    pub fn ct_add(&self, rhs: &Self, byte_length: usize) -> Self {
+        let mut res = vec![0; byte_length];
+        let mut carry: u16 = 0;
+        for i in (0..byte_length).rev() {
+            let sum = byte_at(self, byte_length, i) as u16
+                + byte_at(rhs, byte_length, i) as u16
+                + carry;
+            res[i] = sum as u8;
+            carry = sum >> 8;
+        }
+        Base256::new(res)
+    }
+
+    /// `(self - rhs) mod 2^(8 * byte_length)` as exactly `byte_length` bytes, wrapping below zero
+    /// rather than panicking, in time that depends only on the lengths.
+    pub fn ct_sub(&self, rhs: &Self, byte_length: usize) -> Self {
+        let mut res = vec![0; byte_length];
+        let mut borrow: u16 = 0;
+        for i in (0..byte_length).rev() {
+            let diff = (byte_at(self, byte_length, i) as u16)
+                .wrapping_sub(byte_at(rhs, byte_length, i) as u16)
+                .wrapping_sub(borrow);
+            res[i] = diff as u8;
+            // A negative difference wraps to 0xff00 or above.
+            borrow = diff >> 15;
+        }
+        Base256::new(res)
+    }
+
+    /// `a` if `choice` is unset and `b` if it is set, without branching on `choice`.
+    ///
+    /// Panics if the lengths differ; lengths are treated as public.
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        if a.len() != b.len() {
+            panic!("Operands must have the same length")
+        }
+        Base256::new(
+            a.iter()
+                .zip(b.iter())
+                .map(|(x, y)| u8::conditional_select(x, y, choice))
+                .collect(),
+        )
+    }
+}
+
+/// Equal only for identical bytes, leading zeros included, as with `PartialEq`. Lengths are
+/// treated as public: values of different lengths return early.
+/// ```
+/// use byte_arithmetic::Base256;
+/// use subtle::{ConstantTimeEq, ConstantTimeGreater};
+/// let a = Base256::new(vec![0x12, 0x34]);
+/// assert!(bool::from(a.ct_eq(&Base256::new(vec![0x12, 0x34]))));
+/// assert!(bool::from(a.ct_gt(&Base256::new(vec![0x12, 0x33]))));
+/// ```
+impl ConstantTimeEq for Base256 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.inner.as_slice().ct_eq(other.inner.as_slice())
+    }
+}
+
+/// Numeric comparison. Intended for equal-length values; shorter values are read with leading
+/// zeros, and only their lengths affect timing.
+impl ConstantTimeGreater for Base256 {
+    fn ct_gt(&self, other: &Self) -> Choice {
+        ct_gt_bytes(&self.inner, &other.inner)
+    }
+}
+
+impl ConstantTimeLess for Base256 {}
+
+impl<const N: usize> ConstantTimeEq for FixedBase256<N> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.bytes.ct_eq(&other.bytes)
+    }
+}
+
+impl<const N: usize> ConstantTimeGreater for FixedBase256<N> {
+    fn ct_gt(&self, other: &Self) -> Choice {
+        ct_gt_bytes(&self.bytes, &other.bytes)
+    }
+}
+
+impl<const N: usize> ConstantTimeLess for FixedBase256<N> {}
+
+impl<const N: usize> ConditionallySelectable for FixedBase256<N> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut bytes = [0; N];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::conditional_select(&a.bytes[i], &b.bytes[i], choice);
+        }
+        FixedBase256::from_be_bytes(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ct_matches_cmp() {
+        let values = [[0u8, 0], [0, 1], [0, 255], [1, 0], [255, 255]];
+        for a in values.iter() {
+            for b in values.iter() {
+                let (x, y) = (Base256::from(*a), Base256::from(*b));
+                assert_eq!(bool::from(x.ct_eq(&y)), a == b);
+                assert_eq!(bool::from(x.ct_gt(&y)), a > b);
+                assert_eq!(bool::from(x.ct_lt(&y)), a < b);
+                let (x, y) = (FixedBase256::from_be_bytes(*a), FixedBase256::from_be_bytes(*b));
+                assert_eq!(bool::from(x.ct_gt(&y)), a > b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ct_add_sub_match_wrapping() {
+        for a in [0u16, 1, 255, 256, 0x7fff, 0xffff] {
+            for b in [0u16, 1, 2, 255, 0x8001, 0xffff] {
+                let (x, y) = (Base256::from(a), Base256::from(b));
+                assert_eq!(x.ct_add(&y, 2), Base256::from(a.wrapping_add(b).to_be_bytes()));
+                assert_eq!(x.ct_sub(&y, 2), Base256::from(a.wrapping_sub(b).to_be_bytes()));
+            }
+        }
+        let wide = Base256::new(vec![9, 0, 1]);
+        assert_eq!(wide.ct_add(&Base256::new(vec![2]), 1), Base256::new(vec![3]));
+        assert_eq!(wide.ct_sub(&Base256::new(vec![2]), 4), Base256::new(vec![0, 8, 255, 255]));
+    }
+
+    #[test]
+    fn test_conditional_select() {
+        let (a, b) = (Base256::new(vec![1, 2]), Base256::new(vec![3, 4]));
+        assert_eq!(Base256::conditional_select(&a, &b, Choice::from(0)), a);
+        assert_eq!(Base256::conditional_select(&a, &b, Choice::from(1)), b);
+        let (x, y) = (FixedBase256::from_be_bytes([1]), FixedBase256::from_be_bytes([2]));
+        assert_eq!(FixedBase256::conditional_select(&x, &y, Choice::from(1)), y);
+    }
+
+    #[test]
+    fn test_ct_unequal_lengths() {
+        let (short, long) = (Base256::new(vec![2]), Base256::new(vec![0, 1]));
+        assert!(!bool::from(short.ct_eq(&Base256::new(vec![0, 2]))));
+        assert!(bool::from(short.ct_gt(&long)));
+        assert!(!bool::from(long.ct_gt(&short)));
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{Base256, OverflowError};
+use ::bytes::{Buf, BufMut, BytesMut};
+use ::tokio_util::codec::{Decoder, Encoder};
+use std::io;
+
+/// Frames a stream of Base256 values for `tokio_util::codec::Framed`.
+///
+/// In length-prefixed mode every frame is a big-endian `u32` byte count followed by the bytes of
+/// the value, including leading zeros. In fixed-width mode every frame is exactly `width` bytes,
+/// i.e. a stream of 32byte IDs with `Base256Codec::fixed(32)`.
+/// ```
+/// use byte_arithmetic::{Base256, Base256Codec};
+/// use bytes::BytesMut;
+/// use tokio_util::codec::{Decoder, Encoder};
+/// let mut codec = Base256Codec::length_prefixed(1024);
+/// let mut buf = BytesMut::new();
+/// codec.encode(Base256::new(vec![0, 7]), &mut buf).unwrap();
+/// assert_eq!(&buf[..], &[0, 0, 0, 2, 0, 7]);
+/// assert_eq!(codec.decode(&mut buf).unwrap(), Some(Base256::new(vec![0, 7])));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Base256Codec {
+    mode: Mode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    LengthPrefixed { max_length: usize },
+    Fixed { width: usize },
+}
+
+impl Base256Codec {
+    /// Length-prefixed frames, rejecting any longer than `max_length` bytes before buffering
+    /// them.
+    pub fn length_prefixed(max_length: usize) -> Self {
+        Base256Codec {
+            mode: Mode::LengthPrefixed { max_length },
+        }
+    }
+
+    /// Frames of exactly `width` bytes. Encoding left-pads narrower values and fails for values
+    /// with more than `width` significant bytes.
+    ///
+    /// Panics if `width` is zero, as every decode would yield a value without consuming input.
+    pub fn fixed(width: usize) -> Self {
+        if width == 0 {
+            panic!("Frames must be at least one byte wide")
+        }
+        Base256Codec {
+            mode: Mode::Fixed { width },
+        }
+    }
+}
+
+impl Decoder for Base256Codec {
+    type Item = Base256;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let (header, len) = match self.mode {
+            Mode::Fixed { width } => (0, width),
+            Mode::LengthPrefixed { max_length } => {
+                if src.len() < 4 {
+                    return Ok(None);
+                }
+                let len = u32::from_be_bytes([src[0], src[1], src[2], src[3]]) as usize;
+                if len > max_length {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("frame of {} bytes exceeds the limit of {}", len, max_length),
+                    ));
+                }
+                (4, len)
+            }
+        };
+        if src.len() < header + len {
+            src.reserve(header + len - src.len());
+            return Ok(None);
+        }
+        src.advance(header);
+        Ok(Some(Base256::from(src.split_to(len).freeze())))
+    }
+}
+
+impl Encoder<&Base256> for Base256Codec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: &Base256, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        match self.mode {
+            Mode::Fixed { width } => {
+                let significant = item.significant_bytes();
+                if significant.len() > width {
+                    let err = OverflowError::new("the frame width", significant.len(), width);
+                    return Err(io::Error::new(io::ErrorKind::InvalidInput, err));
+                }
+                dst.reserve(width);
+                dst.put_bytes(0, width - significant.len());
+                dst.put_slice(significant);
+            }
+            Mode::LengthPrefixed { max_length } => {
+                let len = match u32::try_from(item.len()) {
+                    Ok(len) if item.len() <= max_length => len,
+                    _ => {
+                        let err = OverflowError::new("the frame limit", item.len(), max_length);
+                        return Err(io::Error::new(io::ErrorKind::InvalidInput, err));
+                    }
+                };
+                dst.reserve(4 + item.len());
+                dst.put_u32(len);
+                dst.put_slice(item);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Encoder<Base256> for Base256Codec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: Base256, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.encode(&item, dst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_frames() {
+        let mut codec = Base256Codec::fixed(4);
+        let mut buf = BytesMut::new();
+        codec.encode(Base256::new(vec![1, 2]), &mut buf).unwrap();
+        codec.encode(&Base256::new(vec![0, 0, 0, 0, 3]), &mut buf).unwrap();
+        assert!(codec.encode(Base256::new(vec![1, 0, 0, 0, 0]), &mut buf).is_err());
+        assert_eq!(&buf[..], &[0, 0, 1, 2, 0, 0, 0, 3]);
+        assert_eq!(codec.decode(&mut buf).unwrap(), Some(Base256::new(vec![0, 0, 1, 2])));
+        buf.truncate(2);
+        assert_eq!(codec.decode(&mut buf).unwrap(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Frames must be at least one byte wide")]
+    fn test_fixed_zero_width() {
+        Base256Codec::fixed(0);
+    }
+
+    #[test]
+    fn test_length_prefixed_partial_and_limit() {
+        let mut codec = Base256Codec::length_prefixed(2);
+        let mut buf = BytesMut::from(&[0, 0, 0, 2, 9][..]);
+        assert_eq!(codec.decode(&mut buf).unwrap(), None);
+        buf.put_u8(8);
+        assert_eq!(codec.decode(&mut buf).unwrap(), Some(Base256::new(vec![9, 8])));
+        assert!(buf.is_empty());
+        buf.put_u32(3);
+        let err = codec.decode(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(codec.encode(Base256::new(vec![1, 2, 3]), &mut buf).is_err());
+    }
+}
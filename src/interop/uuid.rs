@@ -0,0 +1,42 @@
+use crate::{Base256, OverflowError};
+use ::uuid::Uuid;
+
+/// UUIDs convert to their 16 big-endian bytes, so time-ordered UUIDv7 values order and subtract
+/// numerically.
+/// ```
+/// use byte_arithmetic::Base256;
+/// use uuid::Uuid;
+/// let earlier = Uuid::from_u128(0x0189_0000_0000_7000_8000_0000_0000_0000);
+/// let later = Uuid::from_u128(0x0189_0000_0001_7000_8000_0000_0000_0000);
+/// let gap = Base256::from(later) - Base256::from(earlier);
+/// assert_eq!(u128::try_from(&gap), Ok(1 << 80));
+/// assert_eq!(Uuid::try_from(&Base256::from(later)), Ok(later));
+/// ```
+impl From<Uuid> for Base256 {
+    fn from(uuid: Uuid) -> Self {
+        Base256::new(uuid.as_bytes().to_vec())
+    }
+}
+
+impl TryFrom<&Base256> for Uuid {
+    type Error = OverflowError;
+
+    fn try_from(value: &Base256) -> Result<Self, Self::Error> {
+        value.to_array_for("Uuid").map(Uuid::from_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uuid_round_trip() {
+        let uuid = Uuid::from_u128(0xdead_beef);
+        let value = Base256::from(uuid);
+        assert_eq!(value.len(), 16);
+        assert_eq!(Uuid::try_from(&value), Ok(uuid));
+        assert_eq!(Uuid::try_from(&Base256::new(vec![1])), Ok(Uuid::from_u128(1)));
+        assert!(Uuid::try_from(&Base256::new(vec![1; 17])).is_err());
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{Base256, FixedBase256};
+use ::zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Clears the value and zeroes the whole allocation, including spare capacity.
+impl Zeroize for Base256 {
+    fn zeroize(&mut self) {
+        self.inner.zeroize();
+    }
+}
+
+/// With this feature every Base256 zeroes its buffer when dropped, whether it was handed to the
+/// caller or only used inside an operation.
+///
+/// Also scrubbed:
+/// - buffers replaced when a value is padded, including by `+=` and `-=`, or by `reserve` and
+///   `shrink_to_fit`
+/// - the `u64` limb buffers used by multiplication: both operands, the product, and the scratch
+///   of the Karatsuba, number-theoretic transform and `rayon` kernels
+///
+/// Not scrubbed: plain byte and digit vectors that never become a Base256, such as the
+/// fixed-width copies taken by the ring, interval, Morton and batch helpers, the digit vectors
+/// of radix conversion and encodings, and words held on the stack or in registers.
+impl Drop for Base256 {
+    fn drop(&mut self) {
+        self.inner.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for Base256 {}
+
+/// `FixedBase256` is `Copy` and so cannot zero itself on drop; call `zeroize` explicitly.
+impl<const N: usize> Zeroize for FixedBase256<N> {
+    fn zeroize(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zeroize() {
+        let mut value = Base256::new(vec![1, 2, 3]);
+        value.zeroize();
+        assert!(value.is_empty());
+        let mut fixed = FixedBase256::from_be_bytes([1, 2]);
+        fixed.zeroize();
+        assert_eq!(fixed, FixedBase256::ZERO);
+        let bytes: Vec<u8> = Base256::new(vec![4, 5]).into();
+        assert_eq!(bytes, vec![4, 5]);
+    }
+}
@@ -0,0 +1,425 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::{cmp_numeric, Base256};
+use core::cmp::Ordering;
+
+/// Whether an [`Interval`] includes the ID at one of its ends.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Endpoint {
+    Open,
+    Closed,
+}
+
+/// Clockwise arc of a ring of `byte_length` byte IDs, from `start` round to `end`.
+///
+/// When `start > end` the arc wraps through zero. When `start == end` it is the whole ring, so
+/// a lone node owns every key; the shared endpoint is included if either end is closed.
+///
+/// Intervals default to `[start, end)`, matching [`crate::Base256Range`]. Chord's "between my
+/// predecessor and me" is `(predecessor, self]`:
+/// ```
+/// use byte_arithmetic::{Base256, Endpoint, Interval};
+/// let predecessor = Base256::new(vec![0xf0]);
+/// let node = Base256::new(vec![0x10]);
+/// let owned = Interval::new(predecessor, node, 1)
+///     .with_endpoints(Endpoint::Open, Endpoint::Closed);
+/// assert!(owned.contains(&Base256::new(vec![0xff])));
+/// assert!(owned.contains(&Base256::new(vec![0x10])));
+/// assert!(!owned.contains(&Base256::new(vec![0xf0])));
+/// assert!(!owned.contains(&Base256::new(vec![0x80])));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Interval {
+    start: Base256,
+    end: Base256,
+    byte_length: usize,
+    start_endpoint: Endpoint,
+    end_endpoint: Endpoint,
+}
+
+impl Interval {
+    /// `[start, end)` on a ring of `byte_length` bytes. Endpoints are wrapped to that width.
+    pub fn new(start: Base256, end: Base256, byte_length: usize) -> Self {
+        Interval {
+            start: Base256::new(start.wrapped_bytes(byte_length)),
+            end: Base256::new(end.wrapped_bytes(byte_length)),
+            byte_length,
+            start_endpoint: Endpoint::Closed,
+            end_endpoint: Endpoint::Open,
+        }
+    }
+
+    pub fn with_endpoints(mut self, start: Endpoint, end: Endpoint) -> Self {
+        self.start_endpoint = start;
+        self.end_endpoint = end;
+        self
+    }
+
+    pub fn start(&self) -> &Base256 {
+        &self.start
+    }
+
+    pub fn end(&self) -> &Base256 {
+        &self.end
+    }
+
+    pub fn byte_length(&self) -> usize {
+        self.byte_length
+    }
+
+    pub fn start_endpoint(&self) -> Endpoint {
+        self.start_endpoint
+    }
+
+    pub fn end_endpoint(&self) -> Endpoint {
+        self.end_endpoint
+    }
+
+    /// Whether `id`, wrapped to the ring's width, lies on the arc.
+    pub fn contains(&self, id: &Base256) -> bool {
+        let offset = self.start.ring_distance(id, self.byte_length);
+        let span = self.start.ring_distance(&self.end, self.byte_length);
+        let at_start = is_zero(&offset);
+        if is_zero(&span) {
+            return !at_start
+                || self.start_endpoint == Endpoint::Closed
+                || self.end_endpoint == Endpoint::Closed;
+        }
+        if at_start {
+            return self.start_endpoint == Endpoint::Closed;
+        }
+        // Both distances are `byte_length` bytes, so byte order is numeric order.
+        match offset.cmp(&span) {
+            Ordering::Less => true,
+            Ordering::Equal => self.end_endpoint == Endpoint::Closed,
+            Ordering::Greater => false,
+        }
+    }
+
+    /// Whether no ID lies on the arc: only `(x, x + 1)`, or `(x, x)` on a zero byte ring.
+    pub fn is_empty(&self) -> bool {
+        if self.start_endpoint == Endpoint::Closed || self.end_endpoint == Endpoint::Closed {
+            return false;
+        }
+        let span = self.start.ring_distance(&self.end, self.byte_length);
+        match span.significant_bytes() {
+            [] => self.byte_length == 0,
+            [1] => true,
+            _ => false,
+        }
+    }
+
+    /// Split at `point` into the arc before it and the arc starting at it, so `point` goes to
+    /// the second half. `None` unless `point` lies strictly between the endpoints.
+    /// ```
+    /// use byte_arithmetic::{Base256, Interval};
+    /// let range = Interval::new(Base256::new(vec![0xf0]), Base256::new(vec![0x10]), 1);
+    /// let (low, high) = range.split_at(&Base256::new(vec![0])).unwrap();
+    /// assert_eq!((low.start(), low.end()), (range.start(), &Base256::new(vec![0])));
+    /// assert_eq!((high.start(), high.end()), (&Base256::new(vec![0]), range.end()));
+    /// assert_eq!(range.split_at(&Base256::new(vec![0x80])), None);
+    /// ```
+    pub fn split_at(&self, point: &Base256) -> Option<(Interval, Interval)> {
+        let offset = self.start.ring_distance(point, self.byte_length);
+        let span = self.start.ring_distance(&self.end, self.byte_length);
+        if is_zero(&offset) || (!is_zero(&span) && offset >= span) {
+            return None;
+        }
+        Some((
+            self.arc(self.start.clone(), point.clone(), self.start_endpoint, Endpoint::Open),
+            self.arc(point.clone(), self.end.clone(), Endpoint::Closed, self.end_endpoint),
+        ))
+    }
+
+    /// Split into `n` consecutive arcs of equal span, give or take one, in clockwise order.
+    ///
+    /// Inner boundaries belong to the arc they start. Panics if `n` is zero or exceeds the span.
+    /// ```
+    /// use byte_arithmetic::{Base256, Interval};
+    /// let ring = Interval::new(Base256::new(vec![0x40]), Base256::new(vec![0x40]), 1);
+    /// let starts: Vec<Base256> = ring.split_even(4).iter().map(|x| x.start().clone()).collect();
+    /// assert_eq!(starts, [0x40, 0x80, 0xc0, 0x00].map(|x| Base256::new(vec![x])));
+    /// ```
+    pub fn split_even(&self, n: usize) -> Vec<Interval> {
+        if n == 0 {
+            panic!("Cannot split into zero intervals")
+        }
+        let mut span = self.start.ring_distance(&self.end, self.byte_length);
+        if is_zero(&span) {
+            let mut full = vec![0; self.byte_length + 1];
+            full[0] = 1;
+            span = Base256::new(full);
+        }
+        if cmp_numeric(&span, &Base256::from(n as u64)) == Ordering::Less {
+            panic!("Cannot split into more intervals than the span")
+        }
+        let boundaries: Vec<Base256> = (0..n as u64)
+            .map(|i| {
+                let (offset, _) = span.mul_u64(i).div_rem_u64(n as u64);
+                (&self.start + offset).wrap_to(self.byte_length)
+            })
+            .collect();
+        (0..n)
+            .map(|i| {
+                let start_endpoint = if i == 0 { self.start_endpoint } else { Endpoint::Closed };
+                let (end, end_endpoint) = match boundaries.get(i + 1) {
+                    Some(next) => (next.clone(), Endpoint::Open),
+                    None => (self.end.clone(), self.end_endpoint),
+                };
+                self.arc(boundaries[i].clone(), end, start_endpoint, end_endpoint)
+            })
+            .collect()
+    }
+
+    /// Join with an arc that starts where this one ends, or ends where this one starts, as when
+    /// a node leaves and its successor absorbs its range.
+    ///
+    /// `None` if the arcs are on different rings, do not meet, leave out the ID they meet at, or
+    /// either is already the whole ring. Arcs that together reach round to where they began
+    /// give the whole ring, less the starting ID if both outer ends leave it out.
+    /// ```
+    /// use byte_arithmetic::{Base256, Interval};
+    /// let a = Interval::new(Base256::new(vec![0x10]), Base256::new(vec![0x20]), 1);
+    /// let b = Interval::new(Base256::new(vec![0x20]), Base256::new(vec![0x30]), 1);
+    /// let merged = Interval::new(Base256::new(vec![0x10]), Base256::new(vec![0x30]), 1);
+    /// assert_eq!(a.merge(&b), Some(merged.clone()));
+    /// assert_eq!(b.merge(&a), Some(merged));
+    /// ```
+    pub fn merge(&self, adjacent: &Interval) -> Option<Interval> {
+        if self.byte_length != adjacent.byte_length || self.is_full() || adjacent.is_full() {
+            return None;
+        }
+        let joins = |first: &Interval, second: &Interval| {
+            first.end == second.start
+                && (first.end_endpoint == Endpoint::Closed
+                    || second.start_endpoint == Endpoint::Closed)
+        };
+        let (first, second) = if joins(self, adjacent) {
+            (self, adjacent)
+        } else if joins(adjacent, self) {
+            (adjacent, self)
+        } else {
+            return None;
+        };
+        let span = |x: &Interval| x.start.ring_distance(&x.end, self.byte_length);
+        let mut ring = vec![0; self.byte_length + 1];
+        ring[0] = 1;
+        match cmp_numeric(&(span(first) + span(second)), &ring) {
+            Ordering::Less => {}
+            Ordering::Equal
+                if first.start_endpoint == Endpoint::Open
+                    && second.end_endpoint == Endpoint::Open =>
+            {
+                let start = first.start.clone();
+                return Some(self.arc(start.clone(), start, Endpoint::Open, Endpoint::Open));
+            }
+            _ => {
+                let start = first.start.clone();
+                return Some(Interval::new(start.clone(), start, self.byte_length));
+            }
+        }
+        Some(self.arc(
+            first.start.clone(),
+            second.end.clone(),
+            first.start_endpoint,
+            second.end_endpoint,
+        ))
+    }
+
+    /// Whether some ID lies on both arcs. Arcs on rings of different widths never overlap.
+    /// ```
+    /// use byte_arithmetic::{Base256, Interval};
+    /// let a = Interval::new(Base256::new(vec![0xf0]), Base256::new(vec![0x10]), 1);
+    /// let b = Interval::new(Base256::new(vec![0x08]), Base256::new(vec![0x20]), 1);
+    /// let c = Interval::new(Base256::new(vec![0x10]), Base256::new(vec![0x20]), 1);
+    /// assert!(a.overlaps(&b));
+    /// assert!(!a.overlaps(&c));
+    /// ```
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        if self.byte_length != other.byte_length {
+            return false;
+        }
+        // Two arcs meet exactly when one contains the first ID of the other.
+        match (self.first(), other.first()) {
+            (Some(x), Some(y)) => other.contains(&x) || self.contains(&y),
+            _ => false,
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// The first ID clockwise on the arc, if any.
+    fn first(&self) -> Option<Base256> {
+        if self.is_empty() {
+            return None;
+        }
+        match self.start_endpoint {
+            Endpoint::Closed => Some(self.start.clone()),
+            Endpoint::Open => Some(self.start.wrapping_inc(self.byte_length)),
+        }
+    }
+
+    /// An arc on the same ring.
+    fn arc(
+        &self,
+        start: Base256,
+        end: Base256,
+        start_endpoint: Endpoint,
+        end_endpoint: Endpoint,
+    ) -> Interval {
+        Interval::new(start, end, self.byte_length).with_endpoints(start_endpoint, end_endpoint)
+    }
+}
+
+fn is_zero(value: &Base256) -> bool {
+    value.iter().all(|x| *x == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(start: u8, end: u8, start_endpoint: Endpoint, end_endpoint: Endpoint) -> Interval {
+        Interval::new(Base256::new(vec![start]), Base256::new(vec![end]), 1)
+            .with_endpoints(start_endpoint, end_endpoint)
+    }
+
+    #[test]
+    fn test_contains_matches_brute_force() {
+        use Endpoint::*;
+        for (start, end) in [(10u8, 20u8), (250, 5), (7, 7), (0, 255), (255, 0)] {
+            for (s, e) in [(Open, Open), (Open, Closed), (Closed, Open), (Closed, Closed)] {
+                let range = interval(start, end, s, e);
+                for id in 0..=255u8 {
+                    let offset = id.wrapping_sub(start);
+                    let span = end.wrapping_sub(start);
+                    let expected = if offset == 0 {
+                        s == Closed || (span == 0 && e == Closed)
+                    } else if span == 0 {
+                        true
+                    } else {
+                        offset < span || (offset == span && e == Closed)
+                    };
+                    assert_eq!(range.contains(&Base256::new(vec![id])), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_empty() {
+        use Endpoint::*;
+        assert!(interval(4, 5, Open, Open).is_empty());
+        assert!(interval(255, 0, Open, Open).is_empty());
+        assert!(!interval(4, 5, Closed, Open).is_empty());
+        assert!(!interval(4, 4, Open, Open).is_empty());
+        let point = Interval::new(Base256::empty(), Base256::empty(), 0);
+        assert!(point.clone().with_endpoints(Open, Open).is_empty());
+        assert!(point.contains(&Base256::new(vec![9])));
+    }
+
+    #[test]
+    fn test_endpoints_wrap_to_width() {
+        let range = Interval::new(Base256::new(vec![1, 0, 5]), Base256::new(vec![7]), 2);
+        assert_eq!(range.start(), &Base256::new(vec![0, 5]));
+        assert_eq!(range.end(), &Base256::new(vec![0, 7]));
+        assert!(range.contains(&Base256::new(vec![3, 0, 6])));
+    }
+
+    #[test]
+    fn test_split_even_covers_once() {
+        let ranges = [
+            interval(250, 9, Endpoint::Open, Endpoint::Closed),
+            interval(3, 3, Endpoint::Closed, Endpoint::Open),
+        ];
+        for range in ranges {
+            for n in [1, 2, 3, 7] {
+                let parts = range.split_even(n);
+                assert_eq!(parts.len(), n);
+                for id in 0..=255u8 {
+                    let id = Base256::new(vec![id]);
+                    let owners = parts.iter().filter(|x| x.contains(&id)).count();
+                    assert_eq!(owners, range.contains(&id) as usize);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot split into more intervals than the span")]
+    fn test_split_even_too_many() {
+        interval(4, 6, Endpoint::Closed, Endpoint::Closed).split_even(3);
+    }
+
+    #[test]
+    fn test_split_then_merge_round_trips() {
+        let range = interval(200, 100, Endpoint::Open, Endpoint::Closed);
+        let (low, high) = range.split_at(&Base256::new(vec![0])).unwrap();
+        assert!(!low.overlaps(&high));
+        assert_eq!(low.merge(&high), Some(range.clone()));
+        assert_eq!(range.split_at(range.start()), None);
+        assert_eq!(range.split_at(range.end()), None);
+    }
+
+    #[test]
+    fn test_merge_needs_shared_id() {
+        let a = interval(1, 5, Endpoint::Closed, Endpoint::Open);
+        let b = interval(5, 9, Endpoint::Open, Endpoint::Open);
+        assert_eq!(a.merge(&b), None);
+        assert_eq!(a.merge(&interval(6, 9, Endpoint::Closed, Endpoint::Open)), None);
+        let rest = interval(5, 1, Endpoint::Closed, Endpoint::Open);
+        let full = a.merge(&rest).unwrap();
+        assert!(full.contains(&Base256::new(vec![1])));
+        assert_eq!(full.merge(&a), None);
+    }
+
+    #[test]
+    fn test_merge_wrapping_past_the_start_is_full() {
+        let a = interval(0x10, 0x20, Endpoint::Closed, Endpoint::Open);
+        let b = interval(0x20, 0x18, Endpoint::Closed, Endpoint::Open);
+        for merged in [a.merge(&b).unwrap(), b.merge(&a).unwrap()] {
+            assert!((0..=255u8).all(|x| merged.contains(&Base256::new(vec![x]))));
+        }
+        assert_eq!(a.merge(&b), Some(interval(0x10, 0x10, Endpoint::Closed, Endpoint::Open)));
+    }
+
+    #[test]
+    fn test_merge_meeting_at_the_start() {
+        let a = interval(10, 20, Endpoint::Closed, Endpoint::Open);
+        let b = interval(20, 10, Endpoint::Closed, Endpoint::Open);
+        let full = a.merge(&b).unwrap();
+        assert!((0..=255u8).all(|x| full.contains(&Base256::new(vec![x]))));
+        // With both outer ends open only the ID they meet at is left out.
+        let a = a.with_endpoints(Endpoint::Open, Endpoint::Open);
+        let b = b.with_endpoints(Endpoint::Closed, Endpoint::Open);
+        let merged = a.merge(&b).unwrap();
+        let missing: Vec<u8> = (0..=255u8)
+            .filter(|x| !merged.contains(&Base256::new(vec![*x])))
+            .collect();
+        assert_eq!(missing, vec![10]);
+    }
+
+    #[test]
+    fn test_overlaps_matches_brute_force() {
+        use Endpoint::*;
+        let ranges = [
+            interval(10, 20, Closed, Open),
+            interval(20, 30, Open, Closed),
+            interval(250, 12, Closed, Closed),
+            interval(19, 21, Open, Open),
+            interval(20, 21, Open, Open),
+            interval(0, 0, Open, Open),
+        ];
+        for a in &ranges {
+            for b in &ranges {
+                let shared = (0..=255u8).any(|x| {
+                    let x = Base256::new(vec![x]);
+                    a.contains(&x) && b.contains(&x)
+                });
+                assert_eq!(a.overlaps(b), shared);
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{Base256, OverflowError};
+use std::io::{self, Read, Write};
+
+impl Base256 {
+    /// Write the value as exactly `width` big-endian bytes, left-padded with zeros.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidInput`], wrapping an [`OverflowError`], if the value
+    /// has more than `width` significant bytes; nothing is written in that case.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let mut socket = vec![];
+    /// Base256::new(vec![0x12, 0x34]).write_be_to(&mut socket, 4).unwrap();
+    /// assert_eq!(socket, vec![0, 0, 0x12, 0x34]);
+    /// assert_eq!(Base256::read_be_from(&mut socket.as_slice(), 4).unwrap(), Base256::new(socket));
+    /// ```
+    pub fn write_be_to(&self, w: &mut impl Write, width: usize) -> io::Result<()> {
+        let significant = self.significant_bytes();
+        if significant.len() > width {
+            let err = OverflowError::new("the output width", significant.len(), width);
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, err));
+        }
+        let padding = [0u8; 64];
+        let mut remaining = width - significant.len();
+        while remaining > 0 {
+            let chunk = remaining.min(padding.len());
+            w.write_all(&padding[..chunk])?;
+            remaining -= chunk;
+        }
+        w.write_all(significant)
+    }
+
+    /// Read exactly `width` big-endian bytes, keeping any leading zeros.
+    ///
+    /// Fails with [`io::ErrorKind::UnexpectedEof`] if the reader ends first.
+    pub fn read_be_from(r: &mut impl Read, width: usize) -> io::Result<Self> {
+        let mut res = vec![0; width];
+        r.read_exact(&mut res)?;
+        Ok(Base256::new(res))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_be_to() {
+        let mut out = vec![];
+        let value = Base256::new(vec![0, 0, 1, 2]);
+        value.write_be_to(&mut out, 2).unwrap();
+        value.write_be_to(&mut out, 100).unwrap();
+        assert_eq!(out.len(), 102);
+        assert_eq!(out[..2], [1, 2]);
+        assert_eq!(out[100..], [1, 2]);
+        let err = value.write_be_to(&mut out, 1).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(out.len(), 102);
+    }
+
+    #[test]
+    fn test_read_be_from() {
+        let mut input: &[u8] = &[0, 1, 2, 3, 4];
+        assert_eq!(Base256::read_be_from(&mut input, 3).unwrap(), Base256::new(vec![0, 1, 2]));
+        let err = Base256::read_be_from(&mut input, 3).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
@@ -0,0 +1,169 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::Base256;
+use core::borrow::Borrow;
+
+/// Iterator extension for summing fixed width values, i.e. 32byte hashes.
+///
+/// Every step wraps to `byte_length`, so intermediate values never grow beyond the width.
+/// ```
+/// use byte_arithmetic::{Base256, WrappedSum};
+/// let hashes = vec![Base256::new(vec![255, 255]), Base256::new(vec![0, 2])];
+/// assert_eq!(hashes.into_iter().wrapped_sum(2), Base256::new(vec![0, 1]));
+/// ```
+pub trait WrappedSum: Iterator<Item = Base256> + Sized {
+    fn wrapped_sum(self, byte_length: usize) -> Base256 {
+        self.wrapped_fold(Base256::new(vec![0]), byte_length)
+    }
+
+    fn wrapped_fold(self, init: Base256, byte_length: usize) -> Base256 {
+        self.fold(init, |acc, x| acc.wrapped_add(x, byte_length))
+    }
+}
+
+impl<I: Iterator<Item = Base256>> WrappedSum for I {}
+
+/// Iterator extension for [`Base256::k_closest`], over owned or borrowed IDs.
+/// ```
+/// use byte_arithmetic::{Base256, KClosest};
+/// let target = Base256::new(vec![0x10]);
+/// let peers = vec![Base256::new(vec![0x80]), Base256::new(vec![0x12])];
+/// assert_eq!(peers.into_iter().k_closest(&target, 1), vec![Base256::new(vec![0x12])]);
+/// ```
+pub trait KClosest<T: Borrow<Base256>>: Iterator<Item = T> + Sized {
+    fn k_closest(self, target: &Base256, k: usize) -> Vec<T> {
+        Base256::k_closest(target, self, k)
+    }
+}
+
+impl<T: Borrow<Base256>, I: Iterator<Item = T>> KClosest<T> for I {}
+
+/// Sums start from `[0]`, matching [`Base256::scalar_multiply`], and grow like repeated `+`.
+/// ```
+/// use byte_arithmetic::Base256;
+/// let hashes = vec![Base256::new(vec![255]), Base256::new(vec![1]), Base256::new(vec![2])];
+/// assert_eq!(hashes.iter().sum::<Base256>(), Base256::new(vec![1, 2]));
+/// assert_eq!(hashes.into_iter().product::<Base256>(), Base256::new(vec![1, 254]));
+/// ```
+impl core::iter::Sum for Base256 {
+    fn sum<I: Iterator<Item = Base256>>(iter: I) -> Self {
+        iter.fold(Base256::new(vec![0]), |mut acc, x| {
+            acc += x;
+            acc
+        })
+    }
+}
+
+impl<'a> core::iter::Sum<&'a Base256> for Base256 {
+    fn sum<I: Iterator<Item = &'a Base256>>(iter: I) -> Self {
+        iter.fold(Base256::new(vec![0]), |mut acc, x| {
+            acc += x;
+            acc
+        })
+    }
+}
+
+impl core::iter::Product for Base256 {
+    fn product<I: Iterator<Item = Base256>>(iter: I) -> Self {
+        iter.fold(Base256::new(vec![1]), |acc, x| acc * x)
+    }
+}
+
+impl<'a> core::iter::Product<&'a Base256> for Base256 {
+    fn product<I: Iterator<Item = &'a Base256>>(iter: I) -> Self {
+        iter.fold(Base256::new(vec![1]), |acc, x| acc * x)
+    }
+}
+
+impl Base256 {
+    /// Combine `values` with `f` pairwise in a balanced tree, or `None` if there are none.
+    ///
+    /// Neighbours are joined as soon as they cover the same number of values, so the left side of
+    /// every join is a complete tree of a power of two values and any remainder is joined on the
+    /// right at the end, as Merkle trees do. Order is kept: `f` always gets the earlier value
+    /// first. Compared to a linear fold, an unwrapped sum only ever adds values of similar size,
+    /// and only `log2(n)` partial results are held at once.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let values = (1..=5u8).map(|x| Base256::new(vec![x]));
+    /// let mut joins = Vec::new();
+    /// let total = Base256::tree_fold(values, |a, b| {
+    ///     joins.push((a[0], b[0]));
+    ///     &a + &b
+    /// });
+    /// assert_eq!(total, Some(Base256::new(vec![15])));
+    /// assert_eq!(joins, [(1, 2), (3, 4), (3, 7), (10, 5)]);
+    /// ```
+    pub fn tree_fold<I, F>(values: I, mut f: F) -> Option<Base256>
+    where
+        I: IntoIterator<Item = Base256>,
+        F: FnMut(Base256, Base256) -> Base256,
+    {
+        // Partial results with the number of values each covers, halving in size upwards.
+        let mut stack: Vec<(usize, Base256)> = Vec::new();
+        for value in values {
+            let mut node = (1, value);
+            while let Some((count, _)) = stack.last() {
+                if *count != node.0 {
+                    break;
+                }
+                let (count, left) = stack.pop().unwrap();
+                node = (2 * count, f(left, node.1));
+            }
+            stack.push(node);
+        }
+        let (_, mut res) = stack.pop()?;
+        while let Some((_, left)) = stack.pop() {
+            res = f(left, res);
+        }
+        Some(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_sum_and_product() {
+        assert_eq!(std::iter::empty::<Base256>().sum::<Base256>(), Base256::new(vec![0]));
+        assert_eq!(
+            std::iter::empty::<Base256>().product::<Base256>(),
+            Base256::new(vec![1])
+        );
+    }
+
+    #[test]
+    fn test_sum_keeps_width() {
+        let values = [Base256::new(vec![0, 1]), Base256::new(vec![0, 2])];
+        assert_eq!(values.iter().sum::<Base256>(), Base256::new(vec![0, 3]));
+    }
+
+    #[test]
+    fn test_tree_fold_is_balanced() {
+        // Track the depth of each partial result in its first byte.
+        let leaves = (0..8).map(|_| Base256::new(vec![0]));
+        let depth = Base256::tree_fold(leaves, |a, b| Base256::new(vec![a[0].max(b[0]) + 1]));
+        assert_eq!(depth, Some(Base256::new(vec![3])));
+        let concat = Base256::tree_fold((0..7u8).map(|x| Base256::new(vec![x])), |a, b| {
+            Base256::new([a.as_ref(), b.as_ref()].concat())
+        });
+        assert_eq!(concat, Some(Base256::new((0..7).collect())));
+        assert_eq!(Base256::tree_fold(std::iter::empty(), |a, _| a), None);
+    }
+
+    #[test]
+    fn test_wrapped_fold() {
+        let values = vec![Base256::new(vec![255]); 3];
+        assert_eq!(
+            values.into_iter().wrapped_fold(Base256::new(vec![4]), 1),
+            Base256::new(vec![1])
+        );
+    }
+
+    #[test]
+    fn test_wrapped_sum_never_grows() {
+        let total = core::iter::repeat(Base256::new(vec![255, 255])).take(1000).wrapped_sum(2);
+        assert_eq!(total, Base256::new(vec![252, 24]));
+    }
+}
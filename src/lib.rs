@@ -1,12 +1,89 @@
-use itertools::{EitherOrBoth, Itertools};
-use std::ops::BitXor;
-use serde::{Deserialize, Serialize};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+mod arithmetic;
+mod average;
+mod batch;
+mod buffer;
+mod checksum;
+mod cmp;
+mod compact;
+mod convert;
+mod distance;
+mod encoding;
+mod error;
+mod fixed;
+mod fmt;
+mod hash_ring;
+mod hex;
+mod interop;
+mod interval;
+#[cfg(feature = "std")]
+mod io;
+mod iter;
+mod limbs;
+#[doc(hidden)]
+pub mod macros;
+mod morton;
+mod ntt;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod parse;
+mod pow;
+mod radix;
+mod range;
+mod range_map;
+mod ring;
+#[cfg(feature = "routing")]
+pub mod routing;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod varint;
+mod view;
+#[cfg(feature = "simd")]
+mod wide;
+
+pub use arithmetic::ByteArithmetic;
+pub use batch::Batch256;
+pub use distance::DistanceTo;
+#[cfg(feature = "base32")]
+pub use encoding::Base32Alphabet;
+#[cfg(feature = "base64")]
+pub use encoding::Base64Alphabet;
+pub use error::{ArithmeticError, OverflowError, ParseError};
+pub use fixed::FixedBase256;
+pub use fmt::{Grouped, RawDebug};
+pub use hash_ring::HashRing;
+#[cfg(feature = "proptest")]
+pub use interop::proptest;
+#[cfg(feature = "secrecy")]
+pub use interop::{SecretArithmetic, SecretBase256};
+#[cfg(feature = "tokio")]
+pub use interop::Base256Codec;
+#[cfg(feature = "rand")]
+pub use interop::UniformBase256;
+pub use interval::{Endpoint, Interval};
+pub use iter::{KClosest, WrappedSum};
+pub use range::{Base256Range, StepBy};
+pub use range_map::RangeMap;
+pub use ring::RingDirection;
+#[cfg(feature = "serde")]
+pub use serde::BoundedBase256;
+pub use view::Base256Ref;
 
 /// Base256 Object
 ///
 /// Vec<u8> object that implements a subset of basic arithmetic, namely addition, subtraction,
 /// and integer multiplication.
 ///
+/// Bytes are stored big-endian, most significant byte first. Use `from_le_bytes`/`to_le_bytes`
+/// when interoperating with little-endian formats.
+///
 /// Also implements a wrapped addition around a specific byte length, for the purpose of adding
 /// hashes of a specific size, i.e. 32byte hashes.
 ///
@@ -30,12 +107,12 @@ use serde::{Deserialize, Serialize};
 ///             Base256::new(vec![0, 1]), 3
 ///         ), Base256::new(vec![1, 0, 0]));
 /// ```
-#[derive(PartialEq, Ord, PartialOrd, Eq, Debug, Clone, Hash, Serialize, Deserialize)]
+#[derive(PartialEq, Ord, PartialOrd, Eq, Clone, Hash)]
 pub struct Base256 {
     inner: Vec<u8>,
 }
 
-impl std::ops::Deref for Base256 {
+impl core::ops::Deref for Base256 {
     type Target = Vec<u8>;
 
     fn deref(&self) -> &Self::Target {
@@ -43,9 +120,30 @@ impl std::ops::Deref for Base256 {
     }
 }
 
+impl AsRef<[u8]> for Base256 {
+    fn as_ref(&self) -> &[u8] {
+        &self.inner
+    }
+}
+
+/// Hash, Eq and Ord all match those of the underlying bytes, so a Base256 key in a `HashMap` or
+/// `BTreeMap` can be looked up by slice.
+/// ```
+/// use byte_arithmetic::Base256;
+/// use std::collections::HashMap;
+/// let mut owners = HashMap::new();
+/// owners.insert(Base256::new(vec![1, 2]), "node-a");
+/// assert_eq!(owners.get([1u8, 2].as_slice()), Some(&"node-a"));
+/// ```
+impl core::borrow::Borrow<[u8]> for Base256 {
+    fn borrow(&self) -> &[u8] {
+        &self.inner
+    }
+}
+
 impl From<Base256> for Vec<u8> {
-    fn from(base256: Base256) -> Self {
-        base256.inner
+    fn from(mut base256: Base256) -> Self {
+        core::mem::take(&mut base256.inner)
     }
 }
 
@@ -55,17 +153,69 @@ impl From<Vec<u8>> for Base256 {
     }
 }
 
+impl From<&[u8]> for Base256 {
+    fn from(buffer: &[u8]) -> Self {
+        Base256::new(buffer.to_vec())
+    }
+}
+
 impl Base256 {
-    pub fn new(inner: Vec<u8>) -> Self {
+    pub const fn new(inner: Vec<u8>) -> Self {
         Base256 { inner }
     }
 
-    pub fn empty() -> Self { Base256 { inner: vec![] }}
+    pub const fn empty() -> Self { Base256 { inner: Vec::new() }}
+
+    /// An empty value, as [`Base256::empty`], with room for `capacity` bytes.
+    ///
+    /// In-place operations such as `+=`, `-=` and `wrapped_add` grow into reserved capacity
+    /// before they allocate, so an accumulator reserved up front keeps one buffer throughout.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let mut total = Base256::with_capacity(33);
+    /// let buffer = total.as_ptr();
+    /// for _ in 0..100 {
+    ///     total += Base256::new(vec![0xff; 32]);
+    /// }
+    /// assert_eq!(total.len(), 33);
+    /// assert_eq!(total.as_ptr(), buffer);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Base256 { inner: Vec::with_capacity(capacity) }
+    }
+
+    /// Make room for at least `additional` more bytes.
+    ///
+    /// As with `Vec::reserve`, a replacement buffer at least doubles the capacity, so growing a
+    /// byte at a time stays amortised linear. The old buffer is scrubbed first, as with
+    /// `with_width`.
+    pub fn reserve(&mut self, additional: usize) {
+        if self.inner.capacity() - self.inner.len() < additional {
+            let capacity = core::cmp::max(self.inner.len() + additional, 2 * self.inner.capacity());
+            let mut grown = Vec::with_capacity(capacity);
+            grown.extend_from_slice(&self.inner);
+            scrub(&mut self.inner);
+            self.inner = grown;
+        }
+    }
+
+    /// Release spare capacity. The old buffer is scrubbed first, as with `reserve`.
+    pub fn shrink_to_fit(&mut self) {
+        if self.inner.capacity() > self.inner.len() {
+            let shrunk = self.inner.to_vec();
+            scrub(&mut self.inner);
+            self.inner = shrunk;
+        }
+    }
 
     pub fn scalar_multiply(self, value: u8) -> Self {
+        self.repeated_add(value)
+    }
+
+    fn repeated_add(&self, value: u8) -> Self {
         let mut res = Base256::new(vec![0]);
         for _ in 0..value {
-            res = res + self.clone();
+            res += self;
         }
         res
     }
@@ -78,34 +228,173 @@ impl Base256 {
         res
     }
 
-    pub fn wrapped_add(self, other: Self, byte_length: usize) -> Self {
-        let mut res = self + other;
-        let inner_len = res.inner.len();
+    /// Multiply by a machine word, keeping the width of `self` unless the product overflows it.
+    pub(crate) fn mul_u64(&self, value: u64) -> Self {
+        let mut carry: u128 = 0;
+        let mut res: Vec<u8> = Vec::with_capacity(self.inner.len() + 8);
+        for byte in self.inner.iter().rev() {
+            let product = *byte as u128 * value as u128 + carry;
+            res.push(product as u8);
+            carry = product >> 8;
+        }
+        while carry > 0 {
+            res.push(carry as u8);
+            carry >>= 8;
+        }
+        res.reverse();
+        Base256 { inner: res }
+    }
+
+    /// Short division by a machine word, returning the quotient at the width of `self` and the
+    /// remainder.
+    pub(crate) fn div_rem_u64(&self, divisor: u64) -> (Self, u64) {
+        if divisor == 0 {
+            panic!("Division by zero")
+        }
+        let mut remainder: u128 = 0;
+        let mut res: Vec<u8> = Vec::with_capacity(self.inner.len());
+        for byte in self.inner.iter() {
+            let acc = (remainder << 8) | *byte as u128;
+            res.push((acc / divisor as u128) as u8);
+            remainder = acc % divisor as u128;
+        }
+        (Base256 { inner: res }, remainder as u64)
+    }
+
+    /// Bytes remaining once leading zeros are stripped.
+    pub(crate) fn significant_bytes(&self) -> &[u8] {
+        &self.inner[self.inner.iter().take_while(|x| **x == 0).count()..]
+    }
+
+    /// The lowest `byte_length` bytes of the value, left-padded with zeros, as `wrapped_add`
+    /// would truncate it.
+    pub(crate) fn wrapped_bytes(&self, byte_length: usize) -> Vec<u8> {
+        let len = self.inner.len();
+        if len >= byte_length {
+            self.inner[len - byte_length..].to_vec()
+        } else {
+            let mut res = vec![0; byte_length - len];
+            res.extend_from_slice(&self.inner);
+            res
+        }
+    }
+
+    /// Left-pad with zeros, or strip leading zeros, until the value is `width` bytes long.
+    ///
+    /// Non-zero bytes are never dropped, so the result may remain longer than `width`.
+    pub(crate) fn with_width(mut self, width: usize) -> Self {
+        let len = self.inner.len();
+        if len < width {
+            self.reserve(width - len);
+            self.inner.resize(width, 0);
+            self.inner.rotate_right(width - len);
+        } else if len > width {
+            let zeros = self.inner.iter().take(len - width).take_while(|x| **x == 0).count();
+            self.inner.drain(..zeros);
+        }
+        self
+    }
+
+    pub fn wrapped_add(mut self, other: Self, byte_length: usize) -> Self {
+        self += other;
+        self.wrap_to(byte_length)
+    }
+
+    /// Left-pad in place to at least `width` bytes, within reserved capacity where there is room.
+    /// Goes through `with_width`, so a buffer that has to be replaced is scrubbed first.
+    fn pad_to(&mut self, width: usize) {
+        if self.inner.len() < width {
+            let inner = core::mem::take(&mut self.inner);
+            self.inner = Vec::from(Base256 { inner }.with_width(width));
+        }
+    }
+
+    /// Drop the bytes above `byte_length`, as `wrapped_add` does on overflow.
+    pub(crate) fn wrap_to(mut self, byte_length: usize) -> Self {
+        let inner_len = self.inner.len();
         if inner_len > byte_length {
-            res.inner = res.inner.into_iter().skip(inner_len - byte_length).collect();
+            self.inner.drain(..inner_len - byte_length);
         }
-        res
+        self
     }
 }
 
-impl BitXor for Base256 {
-    type Output = Base256;
+/// Forward the owned and mixed operand combinations of a binary operator to the `&a op &b`
+/// implementation.
+macro_rules! forward_binop {
+    (impl $imp:ident<$rhs:ty>, $method:ident) => {
+        impl core::ops::$imp<$rhs> for Base256 {
+            type Output = Base256;
 
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        Base256::new(
-            self.iter()
-                .zip_longest(rhs.iter())
-                .map(|x| match x {
-                    EitherOrBoth::Both(a, b) => *a ^ *b,
-                    EitherOrBoth::Left(a) => *a,
-                    EitherOrBoth::Right(b) => *b,
-                })
-                .collect(),
-        )
-    }
+            fn $method(self, rhs: $rhs) -> Self::Output {
+                core::ops::$imp::$method(&self, &rhs)
+            }
+        }
+
+        impl core::ops::$imp<&$rhs> for Base256 {
+            type Output = Base256;
+
+            fn $method(self, rhs: &$rhs) -> Self::Output {
+                core::ops::$imp::$method(&self, rhs)
+            }
+        }
+
+        impl core::ops::$imp<$rhs> for &Base256 {
+            type Output = Base256;
+
+            fn $method(self, rhs: $rhs) -> Self::Output {
+                core::ops::$imp::$method(self, &rhs)
+            }
+        }
+    };
+    (impl $imp:ident<$rhs:ty>, $method:ident, borrowed) => {
+        impl core::ops::$imp<$rhs> for Base256 {
+            type Output = Base256;
+
+            fn $method(self, rhs: $rhs) -> Self::Output {
+                core::ops::$imp::$method(&self, rhs)
+            }
+        }
+    };
+}
+
+forward_binop!(impl BitXor<Base256>, bitxor);
+forward_binop!(impl Mul<Base256>, mul);
+forward_binop!(impl Sub<Base256>, sub);
+forward_binop!(impl Add<Base256>, add);
+
+forward_binop!(impl BitXor<&[u8]>, bitxor, borrowed);
+forward_binop!(impl Mul<&[u8]>, mul, borrowed);
+forward_binop!(impl Sub<&[u8]>, sub, borrowed);
+forward_binop!(impl Add<&[u8]>, add, borrowed);
+
+/// Implement a binary operator between a borrowed Base256 and `$rhs`, applying `$func` to the
+/// underlying bytes.
+macro_rules! impl_binop {
+    (impl $imp:ident<$rhs:ty>, $method:ident, $func:ident) => {
+        impl core::ops::$imp<$rhs> for &Base256 {
+            type Output = Base256;
+
+            fn $method(self, rhs: $rhs) -> Self::Output {
+                Base256 {
+                    inner: $func(&self.inner, &rhs),
+                }
+            }
+        }
+    };
 }
 
-impl std::ops::Mul<u8> for Base256 {
+impl_binop!(impl BitXor<&Base256>, bitxor, xor_bytes);
+impl_binop!(impl Mul<&Base256>, mul, mul_bytes);
+impl_binop!(impl Sub<&Base256>, sub, sub_bytes);
+impl_binop!(impl Add<&Base256>, add, add_bytes);
+
+impl_binop!(impl BitXor<&[u8]>, bitxor, xor_bytes);
+impl_binop!(impl Mul<&[u8]>, mul, mul_bytes);
+impl_binop!(impl Sub<&[u8]>, sub, sub_bytes);
+impl_binop!(impl Add<&[u8]>, add, add_bytes);
+
+impl core::ops::Mul<u8> for Base256 {
     type Output = Base256;
 
     fn mul(self, rhs: u8) -> Self::Output {
@@ -113,76 +402,182 @@ impl std::ops::Mul<u8> for Base256 {
     }
 }
 
-impl std::ops::Sub for Base256 {
+impl core::ops::Mul<u8> for &Base256 {
     type Output = Base256;
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        let mut underflow = 0;
-        let mut res: Vec<u8> = Vec::with_capacity(std::cmp::min(self.inner.len(), rhs.inner.len()));
-        if self < rhs {
-            panic!("Underflow")
-        }
-        let mut rev_a = self.inner;
-        let mut rev_b = rhs.inner;
-        rev_a.reverse();
-        rev_b.reverse();
-        for zipped_elem in rev_a.into_iter().zip_longest(rev_b.into_iter()) {
-            let (x, y): (u8, u8) = match zipped_elem {
-                EitherOrBoth::Both(a, b) => (a, b),
-                EitherOrBoth::Left(a) => (a, 0),
-                EitherOrBoth::Right(b) => (0, b),
-            };
-            let (result, local_underflow) = sub_scalar_underflow(x, y, underflow);
-            res.insert(0, result);
-            underflow = local_underflow;
-        }
-        Base256 { inner: res }
+    fn mul(self, rhs: u8) -> Self::Output {
+        self.repeated_add(rhs)
     }
 }
 
-impl std::ops::Add for Base256 {
-    type Output = Base256;
+/// Implement an assignment operator for every right-hand side the binary operator takes, applying
+/// `$func` to `self` in place.
+macro_rules! impl_assign_op {
+    ($(#[$attr:meta])* impl $imp:ident, $method:ident, $func:ident) => {
+        impl core::ops::$imp<&[u8]> for Base256 {
+            fn $method(&mut self, rhs: &[u8]) {
+                $func(self, rhs)
+            }
+        }
 
-    fn add(self, rhs: Self) -> Self::Output {
-        let mut overflow: u8 = 0;
-        let mut res: Vec<u8> = Vec::with_capacity(std::cmp::max(self.inner.len(), rhs.inner.len()));
-        let mut rev_a = self.inner;
-        let mut rev_b = rhs.inner;
-        rev_a.reverse();
-        rev_b.reverse();
-        for zipped_elem in rev_a.into_iter().zip_longest(rev_b.into_iter()) {
-            let (x, y): (u8, u8) = match zipped_elem {
-                EitherOrBoth::Both(a, b) => (a, b),
-                EitherOrBoth::Left(a) => (a, 0),
-                EitherOrBoth::Right(b) => (0, b),
-            };
-            let (result, local_overflow) = add_scalar_overflow(x, y, overflow);
-            res.insert(0, result);
-            overflow = local_overflow;
+        $(#[$attr])*
+        impl core::ops::$imp<&Base256> for Base256 {
+            fn $method(&mut self, rhs: &Base256) {
+                $func(self, &rhs.inner)
+            }
         }
-        if overflow > 0 {
-            res.insert(0, overflow);
+
+        impl core::ops::$imp<Base256> for Base256 {
+            fn $method(&mut self, rhs: Base256) {
+                $func(self, &rhs.inner)
+            }
         }
-        Base256 { inner: res }
+    };
+}
+
+impl_assign_op!(
+    /// Accumulates in the existing buffer, with the same result as `+`. The buffer only moves
+    /// when the right-hand side is wider or the sum carries out of the top byte.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let mut total = Base256::new(vec![0; 4]);
+    /// for hash in [[0xff; 4], [0x01; 4]] {
+    ///     total += &Base256::from(hash);
+    /// }
+    /// assert_eq!(total, Base256::new(vec![1, 1, 1, 1, 0]));
+    /// total -= Base256::new(vec![1, 0]);
+    /// assert_eq!(total, Base256::new(vec![1, 1, 1, 0, 0]));
+    /// ```
+    impl AddAssign, add_assign, add_assign_bytes
+);
+impl_assign_op!(impl SubAssign, sub_assign, sub_assign_bytes);
+
+/// `a += b` in the existing buffer, at the width of the wider operand as with `a + b`.
+fn add_assign_bytes(a: &mut Base256, b: &[u8]) {
+    a.pad_to(b.len());
+    if limbs::add_in_place(&mut a.inner, b) {
+        a.pad_to(a.inner.len() + 1);
+        a.inner[0] = 1;
+    }
+}
+
+/// `a -= b` in the existing buffer, at the width of the wider operand as with `a - b`.
+fn sub_assign_bytes(a: &mut Base256, b: &[u8]) {
+    if cmp_numeric(&a.inner, b) == Ordering::Less {
+        panic!("Underflow")
+    }
+    a.pad_to(b.len());
+    limbs::sub_in_place_bytes(&mut a.inner, b);
+}
+
+/// Pair up the bytes of two iterators until both are exhausted, reading the missing bytes of the
+/// shorter one as zero.
+fn zip_padded<'a, A, B>(a: A, b: B) -> impl Iterator<Item = (u8, u8)> + 'a
+where
+    A: Iterator<Item = &'a u8> + 'a,
+    B: Iterator<Item = &'a u8> + 'a,
+{
+    let (mut a, mut b) = (a.fuse(), b.fuse());
+    core::iter::from_fn(move || match (a.next(), b.next()) {
+        (None, None) => None,
+        (x, y) => Some((x.copied().unwrap_or(0), y.copied().unwrap_or(0))),
+    })
+}
+
+fn xor_bytes(a: &[u8], b: &[u8]) -> Vec<u8> {
+    #[cfg(feature = "simd")]
+    if a.len() == b.len() {
+        return wide::xor_equal(a, b);
+    }
+    zip_padded(a.iter(), b.iter()).map(|(x, y)| x ^ y).collect()
+}
+
+/// Both operands as native integers, when each fits in a `u128`. Counters and other small values
+/// then skip the byte loops entirely.
+fn small_operands(a: &[u8], b: &[u8]) -> Option<(u128, u128)> {
+    Some((limbs::to_u128(a)?, limbs::to_u128(b)?))
+}
+
+/// Multiplication over u64 limbs, by Karatsuba or a number-theoretic transform for long operands.
+/// The product keeps the width of the wider operand, growing only when the value requires it.
+fn mul_bytes(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let width = core::cmp::max(a.len(), b.len());
+    if let Some(product) = small_operands(a, b).and_then(|(x, y)| x.checked_mul(y)) {
+        return limbs::from_u128(product, width);
+    }
+    let (mut x, mut y) = (limbs::to_limbs(a), limbs::to_limbs(b));
+    let mut res = limbs::mul(&x, &y);
+    let product = Base256 { inner: limbs::from_limbs(&res) }.with_width(width);
+    for buffer in [&mut x, &mut y, &mut res] {
+        limbs::scrub_limbs(buffer);
+    }
+    Vec::from(product)
+}
+
+fn sub_bytes(a: &[u8], b: &[u8]) -> Vec<u8> {
+    if cmp_numeric(a, b) == Ordering::Less {
+        panic!("Underflow")
+    }
+    let width = core::cmp::max(a.len(), b.len());
+    if let Some((x, y)) = small_operands(a, b) {
+        return limbs::from_u128(x - y, width);
+    }
+    let mut res: Vec<u8> = Vec::with_capacity(width);
+    res.resize(width - a.len(), 0);
+    res.extend_from_slice(a);
+    limbs::sub_in_place_bytes(&mut res, b);
+    res
+}
+
+fn add_bytes(a: &[u8], b: &[u8]) -> Vec<u8> {
+    if let Some(sum) = small_operands(a, b).and_then(|(x, y)| x.checked_add(y)) {
+        return limbs::from_u128(sum, core::cmp::max(a.len(), b.len()));
+    }
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    // Room for the carry up front, so the buffer is never reallocated and copied.
+    let mut res: Vec<u8> = Vec::with_capacity(long.len() + 1);
+    res.extend_from_slice(long);
+    if limbs::add_in_place(&mut res, short) {
+        res.insert(0, 1);
     }
+    res
+}
+
+/// Zero a buffer, including its spare capacity, before it is released.
+#[cfg(feature = "zeroize")]
+pub(crate) fn scrub(buffer: &mut Vec<u8>) {
+    ::zeroize::Zeroize::zeroize(buffer);
 }
 
-// [1, 5] - [0, 8] = [0, 7]
-// (5, 8, 0) => (7, 1)
+/// Buffers are only scrubbed with the `zeroize` feature.
+#[cfg(not(feature = "zeroize"))]
+pub(crate) fn scrub(_buffer: &mut [u8]) {}
+
+/// Compare two big-endian byte strings by numeric value, ignoring leading zeros.
+pub(crate) fn cmp_numeric(a: &[u8], b: &[u8]) -> Ordering {
+    let a = &a[a.iter().take_while(|x| **x == 0).count()..];
+    let b = &b[b.iter().take_while(|x| **x == 0).count()..];
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+// Byte-at-a-time reference for the word kernels in `limbs`.
+// [1, 5] - [0, 8] = [0, 253]
+// (5, 8, 0) => (253, 1)
 // (1, 0, 1) => (0, 0)
+#[cfg(test)]
 fn sub_scalar_underflow(a: u8, b:u8, underflow: u8) -> (u8, u8) {
     let mut next_underflow = 0;
     let res = match a.checked_sub(b) {
         Some(val) => match val.checked_sub(underflow) {
             Some(val_underflow) => val_underflow,
             None => {
-                let res = (a as u16 + 255) - b as u16;
+                let res = (a as u16 + 256) - b as u16 - underflow as u16;
                 next_underflow += 1;
                 res as u8
             }
         },
         None => {
-            let res = (a as u16 + 255) - b as u16;
+            let res = (a as u16 + 256) - b as u16 - underflow as u16;
             next_underflow += 1;
             res as u8
         }
@@ -190,6 +585,7 @@ fn sub_scalar_underflow(a: u8, b:u8, underflow: u8) -> (u8, u8) {
     (res, next_underflow)
 }
 
+#[cfg(test)]
 fn add_scalar_overflow(a: u8, b: u8, overflow: u8) -> (u8, u8) {
     let mut next_overflow = 0;
     let res = match a.checked_add(b) {
@@ -224,7 +620,9 @@ mod tests {
 
     #[test]
     fn test_scalar_sub_direct() {
-        assert_eq!(sub_scalar_underflow(5, 8, 0), (252, 1));
+        assert_eq!(sub_scalar_underflow(5, 8, 0), (253, 1));
+        assert_eq!(sub_scalar_underflow(5, 8, 1), (252, 1));
+        assert_eq!(sub_scalar_underflow(0, 0, 1), (255, 1));
         assert_eq!(sub_scalar_underflow(1, 0, 0), (1, 0));
         assert_eq!(sub_scalar_underflow(1, 0, 1), (0, 0));
     }
@@ -257,10 +655,46 @@ mod tests {
     fn test_underflow_multiple_sub() {
         assert_eq!(
             Base256::new(vec![200, 200]) - Base256::new(vec![0, 255]),
-            Base256::new(vec![199, 200])
+            Base256::new(vec![199, 201])
+        );
+    }
+
+    #[test]
+    fn test_sub_borrows_a_full_byte() {
+        assert_eq!(Base256::new(vec![1, 0]) - Base256::new(vec![0, 1]), Base256::new(vec![0, 255]));
+        assert_eq!(
+            Base256::new(vec![1, 0, 0]) - Base256::new(vec![0, 0, 1]),
+            Base256::new(vec![0, 255, 255])
+        );
+    }
+
+    #[test]
+    fn test_sub_numeric_underflow_check() {
+        assert_eq!(
+            Base256::new(vec![0, 200]) - Base256::new(vec![5]),
+            Base256::new(vec![0, 195])
+        );
+    }
+
+    #[test]
+    fn test_mul_div_u64() {
+        assert_eq!(
+            Base256::new(vec![1, 0]).mul_u64(0x1_0000),
+            Base256::new(vec![1, 0, 0, 0])
+        );
+        assert_eq!(
+            Base256::new(vec![1, 0, 1]).div_rem_u64(256),
+            (Base256::new(vec![0, 1, 0]), 1)
         );
     }
 
+    #[test]
+    fn test_with_width() {
+        assert_eq!(Base256::new(vec![0, 0, 5]).with_width(1), Base256::new(vec![5]));
+        assert_eq!(Base256::new(vec![1, 5]).with_width(1), Base256::new(vec![1, 5]));
+        assert_eq!(Base256::new(vec![5]).with_width(3), Base256::new(vec![0, 0, 5]));
+    }
+
     #[test]
     fn test_multiple() {
         assert_eq!(
@@ -277,6 +711,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_long_add_sub_round_trip() {
+        let a = Base256::new((0..65536).map(|x| (x % 251) as u8).collect());
+        let b = Base256::new(vec![255; 65536]);
+        let sum = &a + &b;
+        assert_eq!(sum.len(), 65537);
+        assert_eq!(&sum - &b, a.with_width(65537));
+    }
+
+    #[test]
+    fn test_assign_ops_match_binary_ops() {
+        let values = [
+            Base256::new(vec![0xff, 0xff]),
+            Base256::new(vec![0, 0, 1]),
+            Base256::new(vec![7]),
+            Base256::empty(),
+        ];
+        for a in &values {
+            for b in &values {
+                let mut sum = a.clone();
+                sum += b;
+                assert_eq!(sum, a + b);
+                if cmp_numeric(a, b) != Ordering::Less {
+                    let mut difference = a.clone();
+                    difference -= b.clone();
+                    assert_eq!(difference, a - b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Underflow")]
+    fn test_sub_assign_underflow() {
+        let mut value = Base256::new(vec![0, 5]);
+        value -= &[6u8][..];
+    }
+
+    #[test]
+    fn test_capacity_is_kept() {
+        let mut value = Base256::with_capacity(8);
+        value += Base256::new(vec![0xff; 4]);
+        let buffer = value.as_ptr();
+        value = value.with_width(6).wrapped_add(Base256::new(vec![1]), 8);
+        assert_eq!(value, Base256::new(vec![0, 1, 0, 0, 0, 0]));
+        assert_eq!(value.as_ptr(), buffer);
+        value.reserve(16);
+        assert!(value.capacity() >= 22);
+        value.shrink_to_fit();
+        assert_eq!((value.capacity(), value.len()), (6, 6));
+        value.reserve(1);
+        assert!(value.capacity() >= 12);
+    }
+
     #[test]
     fn test_scalar_mult() {
         assert_eq!(
@@ -285,6 +773,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mult() {
+        assert_eq!(
+            Base256::new(vec![1, 0]) * Base256::new(vec![1, 0]),
+            Base256::new(vec![1, 0, 0])
+        );
+        assert_eq!(
+            Base256::new(vec![0, 0, 255]) * Base256::new(vec![255]),
+            Base256::new(vec![0, 254, 1])
+        );
+        assert_eq!(
+            Base256::new(vec![255, 255]) * Base256::new(vec![255, 255]),
+            Base256::new(vec![255, 254, 0, 1])
+        );
+    }
+
+    #[test]
+    fn test_reference_ops() {
+        let a = Base256::new(vec![1, 255]);
+        let b = Base256::new(vec![3]);
+        assert_eq!(&a + &b, Base256::new(vec![2, 2]));
+        assert_eq!(a.clone() + &b, &a + b.clone());
+        assert_eq!(&a - &b, Base256::new(vec![1, 252]));
+        assert_eq!(&a ^ &b, Base256::new(vec![2, 255]));
+        assert_eq!(&a * &b, Base256::new(vec![5, 253]));
+        assert_eq!(&a * 3, a.clone() * 3);
+        assert_eq!(a, Base256::new(vec![1, 255]));
+    }
+
+    #[test]
+    fn test_slice_ops() {
+        let digest: &[u8] = &[0, 1];
+        let a = Base256::new(vec![255, 255]);
+        assert_eq!(&a + digest, Base256::new(vec![1, 0, 0]));
+        assert_eq!(&a - digest, Base256::new(vec![255, 254]));
+        assert_eq!(&a ^ digest, Base256::new(vec![255, 254]));
+        assert_eq!(a.clone() * digest, a);
+        assert_eq!(Base256::from(digest), Base256::new(vec![0, 1]));
+    }
+
+    #[test]
+    fn test_as_ref() {
+        fn checksum(data: impl AsRef<[u8]>) -> u32 {
+            data.as_ref().iter().map(|x| *x as u32).sum()
+        }
+        assert_eq!(checksum(Base256::new(vec![1, 2, 3])), 6);
+        let mut keys = std::collections::BTreeSet::new();
+        keys.insert(Base256::new(vec![0, 4]));
+        assert!(keys.contains([0u8, 4].as_slice()));
+        assert!(!keys.contains([4u8].as_slice()));
+    }
+
     #[test]
     fn test_scalar_wrapped_mult() {
         assert_eq!(
@@ -0,0 +1,305 @@
+//! Word-at-a-time kernels. Values stay stored as big-endian bytes, since `Deref`, `AsRef` and
+//! `Borrow` hand that buffer out directly. Addition and subtraction read and write that buffer
+//! eight bytes at a time in place; multiplication converts operands to little-endian `u64`
+//! limbs, or a single `u128` when they are small enough, only for the duration of the operation.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::ntt;
+
+/// Little-endian `u64` limbs of big-endian `bytes`, the most significant limb zero-padded.
+pub(crate) fn to_limbs(bytes: &[u8]) -> Vec<u64> {
+    bytes
+        .rchunks(8)
+        .map(read_word)
+        .collect()
+}
+
+/// Big-endian bytes of little-endian `limbs`, eight per limb.
+pub(crate) fn from_limbs(limbs: &[u64]) -> Vec<u8> {
+    limbs.iter().rev().flat_map(|x| x.to_be_bytes()).collect()
+}
+
+/// Value of a big-endian chunk of at most eight bytes.
+fn read_word(chunk: &[u8]) -> u64 {
+    chunk.iter().fold(0u64, |acc, x| (acc << 8) | *x as u64)
+}
+
+/// Store the low `chunk.len()` bytes of `value` big-endian.
+fn write_word(chunk: &mut [u8], value: u64) {
+    chunk.copy_from_slice(&value.to_be_bytes()[8 - chunk.len()..]);
+}
+
+/// `a += b` on big-endian bytes, eight at a time from the least significant end, returning the
+/// carry out of the top byte of `a`. The caller guarantees `a` is at least as long as `b`.
+pub(crate) fn add_in_place(a: &mut [u8], b: &[u8]) -> bool {
+    debug_assert!(a.len() >= b.len());
+    let mut words = b.rchunks(8);
+    let mut carry = false;
+    for chunk in a.rchunks_mut(8) {
+        let y = match words.next() {
+            Some(word) => read_word(word),
+            None if !carry => break,
+            None => 0,
+        };
+        // Only the top chunk of `a` can be short, so the sum is taken modulo its own width.
+        let sum = read_word(chunk) as u128 + y as u128 + carry as u128;
+        carry = sum >> (8 * chunk.len()) != 0;
+        write_word(chunk, sum as u64);
+    }
+    carry
+}
+
+/// `a -= b` on big-endian bytes, eight at a time from the least significant end. The caller
+/// guarantees `a` is at least as long as `b` and numerically no smaller.
+pub(crate) fn sub_in_place_bytes(a: &mut [u8], b: &[u8]) {
+    debug_assert!(a.len() >= b.len());
+    let mut words = b.rchunks(8);
+    let mut borrow = false;
+    for chunk in a.rchunks_mut(8) {
+        let y = match words.next() {
+            Some(word) => read_word(word),
+            None if !borrow => break,
+            None => 0,
+        };
+        let mut diff = read_word(chunk) as i128 - y as i128 - borrow as i128;
+        borrow = diff < 0;
+        if borrow {
+            diff += 1 << (8 * chunk.len());
+        }
+        write_word(chunk, diff as u64);
+    }
+    debug_assert!(!borrow);
+}
+
+/// The value of big-endian `bytes` if it is at most 16 bytes long.
+pub(crate) fn to_u128(bytes: &[u8]) -> Option<u128> {
+    if bytes.len() > 16 {
+        return None;
+    }
+    Some(bytes.iter().fold(0u128, |acc, x| (acc << 8) | *x as u128))
+}
+
+/// Big-endian bytes of `value`, left-padded to `width` but never truncated, as `with_width`.
+pub(crate) fn from_u128(value: u128, width: usize) -> Vec<u8> {
+    let significant = 16 - (value.leading_zeros() / 8) as usize;
+    let mut res = vec![0; width.saturating_sub(significant)];
+    res.extend_from_slice(&value.to_be_bytes()[16 - significant..]);
+    res
+}
+
+/// Zero a limb buffer, including its spare capacity, so words of a secret operand don't outlive
+/// the operation.
+#[cfg(feature = "zeroize")]
+pub(crate) fn scrub_limbs(buffer: &mut Vec<u64>) {
+    ::zeroize::Zeroize::zeroize(buffer);
+}
+
+/// Buffers are only scrubbed with the `zeroize` feature.
+#[cfg(not(feature = "zeroize"))]
+pub(crate) fn scrub_limbs(_buffer: &mut [u64]) {}
+
+/// Product of two limb slices, `a.len() + b.len()` limbs long, by the kernel suited to their
+/// size.
+pub(crate) fn mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+    // The transform already beats splitting the work across threads.
+    #[cfg(feature = "rayon")]
+    if 8 * core::cmp::max(a.len(), b.len()) > crate::parallel::THRESHOLD
+        && a.len().min(b.len()) < NTT_THRESHOLD
+    {
+        return crate::parallel::mul_limbs(a, b);
+    }
+    mul_serial(a, b)
+}
+
+/// Operand length in limbs from which Karatsuba beats the schoolbook kernel.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Operand length in limbs, 128 KiB, from which the number-theoretic transform beats Karatsuba.
+const NTT_THRESHOLD: usize = 16 * 1024;
+
+/// Product of two limb slices on the current thread, by schoolbook, Karatsuba or
+/// number-theoretic transform as the shorter operand grows.
+pub(crate) fn mul_serial(a: &[u64], b: &[u64]) -> Vec<u64> {
+    match a.len().min(b.len()) {
+        len if len < KARATSUBA_THRESHOLD => mul_limbs(a, b),
+        len if len < NTT_THRESHOLD => karatsuba(a, b),
+        _ => ntt::mul(a, b),
+    }
+}
+
+/// Karatsuba product of two limb slices, `a.len() + b.len()` limbs long.
+///
+/// Splitting both operands at `m` limbs, `a = a1 * B^m + a0` and likewise for `b`, the middle
+/// term `a0 * b1 + a1 * b0` is `(a0 + a1) * (b0 + b1) - a0 * b0 - a1 * b1`, so three half-size
+/// products replace four.
+fn karatsuba(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let m = long.len() / 2;
+    let mut res = vec![0u64; a.len() + b.len()];
+    if short.len() <= m {
+        // Too lopsided to split both: multiply `short` by balanced slices of `long` instead.
+        for (i, chunk) in long.chunks(short.len()).enumerate() {
+            let mut part = mul_serial(chunk, short);
+            add_shifted(&mut res, &part, i * short.len());
+            scrub_limbs(&mut part);
+        }
+        return res;
+    }
+    let (a0, a1) = long.split_at(m);
+    let (b0, b1) = short.split_at(m);
+    let mut low = mul_serial(a0, b0);
+    let mut high = mul_serial(a1, b1);
+    let (mut sum_a, mut sum_b) = (add_limbs(a0, a1), add_limbs(b0, b1));
+    let mut middle = mul_serial(&sum_a, &sum_b);
+    sub_in_place(&mut middle, &low);
+    sub_in_place(&mut middle, &high);
+    while middle.last() == Some(&0) {
+        middle.pop();
+    }
+    add_shifted(&mut res, &low, 0);
+    add_shifted(&mut res, &middle, m);
+    add_shifted(&mut res, &high, 2 * m);
+    for buffer in [&mut low, &mut high, &mut middle, &mut sum_a, &mut sum_b] {
+        scrub_limbs(buffer);
+    }
+    res
+}
+
+/// `a + b`, one limb longer than the longer operand.
+fn add_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut res = vec![0u64; a.len().max(b.len()) + 1];
+    add_shifted(&mut res, a, 0);
+    add_shifted(&mut res, b, 0);
+    res
+}
+
+/// Subtract `b` from `a` in place. The caller guarantees `a >= b`.
+fn sub_in_place(a: &mut [u64], b: &[u64]) {
+    let mut borrow = false;
+    for (i, x) in a.iter_mut().enumerate() {
+        if i >= b.len() && !borrow {
+            break;
+        }
+        let (diff, first) = x.overflowing_sub(b.get(i).copied().unwrap_or(0));
+        let (diff, second) = diff.overflowing_sub(borrow as u64);
+        *x = diff;
+        borrow = first || second;
+    }
+}
+
+/// Add `part` into `res` starting at limb `offset`. The caller guarantees the sum fits.
+pub(crate) fn add_shifted(res: &mut [u64], part: &[u64], offset: usize) {
+    let mut carry = false;
+    for (i, x) in part.iter().enumerate() {
+        let (sum, first) = res[offset + i].overflowing_add(*x);
+        let (sum, second) = sum.overflowing_add(carry as u64);
+        res[offset + i] = sum;
+        carry = first || second;
+    }
+    let mut i = offset + part.len();
+    while carry {
+        let (sum, overflow) = res[i].overflowing_add(1);
+        res[i] = sum;
+        carry = overflow;
+        i += 1;
+    }
+}
+
+/// Schoolbook product of two limb slices, `a.len() + b.len()` limbs long.
+pub(crate) fn mul_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut res = vec![0u64; a.len() + b.len()];
+    for (i, x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, y) in b.iter().enumerate() {
+            // At most (2^64 - 1)^2 + 2 * (2^64 - 1) = 2^128 - 1, so this never overflows.
+            let acc = res[i + j] as u128 + *x as u128 * *y as u128 + carry;
+            res[i + j] = acc as u64;
+            carry = acc >> 64;
+        }
+        res[i + b.len()] = carry as u64;
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limbs_round_trip() {
+        let bytes: Vec<u8> = (1..=11).collect();
+        let limbs = to_limbs(&bytes);
+        assert_eq!(limbs, vec![0x0405_0607_0809_0a0b, 0x01_0203]);
+        assert_eq!(&from_limbs(&limbs)[5..], &bytes[..]);
+        assert!(to_limbs(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_word_kernels_match_u128() {
+        let values = [0u128, 1, 0xff, u64::MAX as u128, 1 << 64, u128::MAX >> 72];
+        for (width, other) in [(9, 7), (12, 12), (15, 3)] {
+            for x in values {
+                for y in values {
+                    let x = &x.to_be_bytes()[16 - width..];
+                    let y = &y.to_be_bytes()[16 - other..];
+                    let (x_value, y_value) = (to_u128(x).unwrap(), to_u128(y).unwrap());
+                    let mut sum = x.to_vec();
+                    let carry = add_in_place(&mut sum, y);
+                    let expected = x_value + y_value;
+                    assert_eq!(to_u128(&sum), Some(expected % (1 << (8 * width))));
+                    assert_eq!(carry, expected >> (8 * width) != 0);
+                    if x_value >= y_value {
+                        let mut diff = x.to_vec();
+                        sub_in_place_bytes(&mut diff, y);
+                        assert_eq!(to_u128(&diff), Some(x_value - y_value));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_u128_round_trip() {
+        assert_eq!(to_u128(&[1, 2]), Some(0x0102));
+        assert_eq!(to_u128(&[0; 17]), None);
+        assert_eq!(from_u128(0x0102, 4), vec![0, 0, 1, 2]);
+        assert_eq!(from_u128(0x0102, 1), vec![1, 2]);
+        assert_eq!(from_u128(0, 0), Vec::<u8>::new());
+        assert_eq!(from_u128(u128::MAX, 0), vec![0xff; 16]);
+    }
+
+    fn limbs_of(len: usize, seed: u64) -> Vec<u64> {
+        (0..len as u64)
+            .map(|x| x.wrapping_mul(0x9e37_79b9_7f4a_7c15) ^ seed)
+            .collect()
+    }
+
+    #[test]
+    fn test_karatsuba_matches_schoolbook() {
+        for (x, y) in [(32, 32), (33, 32), (100, 77), (200, 40), (40, 200), (64, 1000)] {
+            let (a, b) = (limbs_of(x, 1), limbs_of(y, 2));
+            assert_eq!(karatsuba(&a, &b), mul_limbs(&a, &b));
+        }
+        // All ones carries through every partial sum.
+        let ones = vec![u64::MAX; 150];
+        assert_eq!(karatsuba(&ones, &ones), mul_limbs(&ones, &ones));
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn test_scrub_limbs() {
+        let mut buffer = vec![u64::MAX; 4];
+        scrub_limbs(&mut buffer);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_mul_limbs_matches_u128() {
+        for (a, b) in [(0u64, 5u64), (u64::MAX, u64::MAX), (0xdead_beef, 0x1_0000_0001)] {
+            let product = mul_limbs(&[a], &[b]);
+            let expected = a as u128 * b as u128;
+            assert_eq!(product, vec![expected as u64, (expected >> 64) as u64]);
+        }
+    }
+}
@@ -0,0 +1,136 @@
+//! Support for the [`base256!`](crate::base256) macro. Not public API.
+
+/// Construct a Base256 from a hexadecimal or decimal literal, validated at compile time.
+///
+/// Hexadecimal takes a `0x` prefix and keeps leading zero bytes, as with
+/// [`Base256::from_hex`](crate::Base256::from_hex). Decimal gives the minimal representation.
+/// Underscores may separate digits, and the literal may be quoted.
+/// ```
+/// use byte_arithmetic::{base256, Base256};
+/// assert_eq!(base256!("0x00deadbeef"), Base256::new(vec![0, 0xde, 0xad, 0xbe, 0xef]));
+/// assert_eq!(base256!(0xdead_beef), Base256::from(0xdead_beef_u32));
+/// assert_eq!(base256!(12345678901234567890), Base256::from(12345678901234567890u64));
+/// ```
+/// Invalid digits fail to compile.
+/// ```compile_fail
+/// let _ = byte_arithmetic::base256!("0xabg");
+/// ```
+#[macro_export]
+macro_rules! base256 {
+    ($value:literal) => {{
+        const PARSED: ([u8; $crate::macros::capacity(stringify!($value))], usize) =
+            $crate::macros::parse(stringify!($value));
+        $crate::Base256::new(PARSED.0[PARSED.1..].to_vec())
+    }};
+}
+
+/// The literal's digits and whether they are hexadecimal, with any quotes and prefix removed.
+const fn digits(literal: &str) -> (&[u8], bool) {
+    let mut bytes = literal.as_bytes();
+    if let [b'"', inner @ .., b'"'] = bytes {
+        bytes = inner;
+    }
+    match bytes {
+        [b'0', b'x' | b'X', rest @ ..] => (rest, true),
+        _ => (bytes, false),
+    }
+}
+
+const fn digit_count(digits: &[u8]) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < digits.len() {
+        if digits[i] != b'_' {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// Bytes needed to hold the parsed literal.
+pub const fn capacity(literal: &str) -> usize {
+    let (digits, hex) = digits(literal);
+    let count = digit_count(digits);
+    if count == 0 {
+        panic!("base256! literal has no digits")
+    }
+    if hex {
+        count.div_ceil(2)
+    } else {
+        // Each decimal digit needs log256(10) < 0.416 bytes.
+        count * 416 / 1000 + 1
+    }
+}
+
+/// Big-endian bytes of the literal and the index at which its representation starts.
+pub const fn parse<const N: usize>(literal: &str) -> ([u8; N], usize) {
+    let (digits, hex) = digits(literal);
+    let mut res = [0u8; N];
+    if hex {
+        // Fill from the least significant nibble so odd lengths gain a leading zero.
+        let mut nibble = 0;
+        let mut i = digits.len();
+        while i > 0 {
+            i -= 1;
+            let value = match digits[i] {
+                b'_' => continue,
+                x @ b'0'..=b'9' => x - b'0',
+                x @ b'a'..=b'f' => x - b'a' + 10,
+                x @ b'A'..=b'F' => x - b'A' + 10,
+                _ => panic!("invalid hexadecimal digit in base256! literal"),
+            };
+            res[N - 1 - nibble / 2] |= value << (4 * (nibble % 2));
+            nibble += 1;
+        }
+        return (res, 0);
+    }
+    let mut i = 0;
+    while i < digits.len() {
+        let digit = match digits[i] {
+            b'_' => {
+                i += 1;
+                continue;
+            }
+            x @ b'0'..=b'9' => x - b'0',
+            _ => panic!("invalid decimal digit in base256! literal"),
+        };
+        let mut carry = digit as u16;
+        let mut j = N;
+        while j > 0 {
+            j -= 1;
+            let acc = res[j] as u16 * 10 + carry;
+            res[j] = acc as u8;
+            carry = acc >> 8;
+        }
+        i += 1;
+    }
+    let mut start = 0;
+    while start < N - 1 && res[start] == 0 {
+        start += 1;
+    }
+    (res, start)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Base256;
+
+    #[test]
+    fn test_hex_literals() {
+        assert_eq!(base256!("0xabc"), Base256::new(vec![0x0a, 0xbc]));
+        assert_eq!(base256!(0X0000), Base256::new(vec![0, 0]));
+        assert_eq!(base256!("0x_ff_ff"), Base256::new(vec![255, 255]));
+    }
+
+    #[test]
+    fn test_decimal_literals() {
+        assert_eq!(base256!(0), Base256::new(vec![0]));
+        assert_eq!(base256!("000256"), Base256::new(vec![1, 0]));
+        assert_eq!(base256!(1_000_000), Base256::from(1_000_000u32));
+        assert_eq!(
+            base256!("115792089237316195423570985008687907853269984665640564039457584007913129639935"),
+            Base256::new(vec![255; 32])
+        );
+    }
+}
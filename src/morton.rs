@@ -0,0 +1,107 @@
+use alloc::vec::Vec;
+use crate::Base256;
+
+/// Spread the bits of `x` to the even bit positions of a `u16`.
+fn spread(x: u8) -> u16 {
+    let mut x = x as u16;
+    x = (x | (x << 4)) & 0x0f0f;
+    x = (x | (x << 2)) & 0x3333;
+    (x | (x << 1)) & 0x5555
+}
+
+/// Gather the even bits of `x` into a byte, undoing `spread`.
+fn compact(x: u16) -> u8 {
+    let mut x = x & 0x5555;
+    x = (x | (x >> 1)) & 0x3333;
+    x = (x | (x >> 2)) & 0x0f0f;
+    ((x | (x >> 4)) & 0x00ff) as u8
+}
+
+impl Base256 {
+    /// Morton (Z-order) code of two equal length coordinates, twice as long as either.
+    ///
+    /// Bits alternate starting with `a`, so each bit of `a` sits just above the matching bit of
+    /// `b`. Nearby points mostly share a prefix, which keeps 2D neighbourhoods close in a
+    /// key-ordered store. Panics if the lengths differ.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let (x, y) = (Base256::new(vec![0b1111_0000]), Base256::new(vec![0b1010_1010]));
+    /// let key = Base256::interleave(&x, &y);
+    /// assert_eq!(key, Base256::new(vec![0b1110_1110, 0b0100_0100]));
+    /// ```
+    pub fn interleave(a: &Base256, b: &Base256) -> Self {
+        if a.len() != b.len() {
+            panic!("Operands must have the same length")
+        }
+        let mut res = Vec::with_capacity(2 * a.len());
+        for (x, y) in a.iter().zip(b.iter()) {
+            res.extend_from_slice(&((spread(*x) << 1) | spread(*y)).to_be_bytes());
+        }
+        Base256::new(res)
+    }
+
+    /// Split a Morton code back into its two coordinates, the inverse of
+    /// [`Base256::interleave`].
+    ///
+    /// An odd length reads as if it had a leading zero byte, so both halves always have the same
+    /// length.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let (x, y) = (Base256::new(vec![0x12, 0x34]), Base256::new(vec![0xab, 0xcd]));
+    /// assert_eq!(Base256::interleave(&x, &y).deinterleave(), (x, y));
+    /// ```
+    pub fn deinterleave(&self) -> (Base256, Base256) {
+        let padded = self.wrapped_bytes(self.len() + self.len() % 2);
+        let (a, b) = padded
+            .chunks_exact(2)
+            .map(|pair| {
+                let z = u16::from_be_bytes([pair[0], pair[1]]);
+                (compact(z >> 1), compact(z))
+            })
+            .unzip();
+        (Base256::new(a), Base256::new(b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    /// Interleave one bit at a time.
+    fn reference(a: u16, b: u16) -> u32 {
+        (0..16).fold(0, |acc, i| {
+            acc | (((a >> i) & 1) as u32) << (2 * i + 1) | (((b >> i) & 1) as u32) << (2 * i)
+        })
+    }
+
+    #[test]
+    fn test_interleave_matches_reference() {
+        for (a, b) in [(0u16, 0u16), (0xffff, 0), (0, 0xffff), (0x1234, 0xabcd), (1, 0x8000)] {
+            let key = Base256::interleave(
+                &Base256::new(a.to_be_bytes().to_vec()),
+                &Base256::new(b.to_be_bytes().to_vec()),
+            );
+            assert_eq!(key, Base256::new(reference(a, b).to_be_bytes().to_vec()));
+        }
+        assert_eq!(Base256::interleave(&Base256::empty(), &Base256::empty()), Base256::empty());
+    }
+
+    #[test]
+    fn test_deinterleave_round_trips() {
+        for x in [0u32, 1, 0x8000_0000, 0xdead_beef, u32::MAX] {
+            let key = Base256::new(x.to_be_bytes().to_vec());
+            let (a, b) = key.deinterleave();
+            assert_eq!(a.len(), 2);
+            assert_eq!(Base256::interleave(&a, &b), key);
+        }
+        let (a, b) = Base256::new(vec![0b10, 0xff, 0xff]).deinterleave();
+        assert_eq!((a, b), (Base256::new(vec![1, 0xff]), Base256::new(vec![0, 0xff])));
+    }
+
+    #[test]
+    #[should_panic(expected = "Operands must have the same length")]
+    fn test_interleave_length_mismatch() {
+        Base256::interleave(&Base256::new(vec![1]), &Base256::new(vec![0, 1]));
+    }
+}
@@ -0,0 +1,156 @@
+//! Number-theoretic transform multiplication for very long operands.
+//!
+//! Operands are cut into 16 bit digits and convolved modulo the prime `2^64 - 2^32 + 1`, which
+//! has roots of unity for every power-of-two length up to `2^32`. Each coefficient of the
+//! convolution is at most `n * (2^16 - 1)^2` for `n` digits in the shorter operand, so it is
+//! exact below the prime for any operand that fits in memory.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::limbs;
+
+const P: u64 = 0xffff_ffff_0000_0001;
+/// Generator of the multiplicative group modulo `P`.
+const G: u64 = 7;
+const DIGIT_BITS: usize = 16;
+const DIGITS_PER_LIMB: usize = 64 / DIGIT_BITS;
+
+fn mul_mod(a: u64, b: u64) -> u64 {
+    (a as u128 * b as u128 % P as u128) as u64
+}
+
+fn add_mod(a: u64, b: u64) -> u64 {
+    // On overflow the wrapped subtraction lands on `a + b - P` as well.
+    let (sum, overflow) = a.overflowing_add(b);
+    if overflow || sum >= P {
+        sum.wrapping_sub(P)
+    } else {
+        sum
+    }
+}
+
+fn sub_mod(a: u64, b: u64) -> u64 {
+    if a >= b {
+        a - b
+    } else {
+        a.wrapping_sub(b).wrapping_add(P)
+    }
+}
+
+fn pow_mod(mut base: u64, mut exp: u64) -> u64 {
+    let mut res = 1;
+    while exp > 0 {
+        if exp % 2 == 1 {
+            res = mul_mod(res, base);
+        }
+        base = mul_mod(base, base);
+        exp /= 2;
+    }
+    res
+}
+
+/// In-place iterative transform of a power-of-two length slice, or its inverse.
+fn transform(values: &mut [u64], invert: bool) {
+    let n = values.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        let mut root = pow_mod(G, (P - 1) / len as u64);
+        if invert {
+            root = pow_mod(root, P - 2);
+        }
+        for chunk in values.chunks_exact_mut(len) {
+            let (low, high) = chunk.split_at_mut(len / 2);
+            let mut w = 1;
+            for (x, y) in low.iter_mut().zip(high.iter_mut()) {
+                let (u, v) = (*x, mul_mod(*y, w));
+                *x = add_mod(u, v);
+                *y = sub_mod(u, v);
+                w = mul_mod(w, root);
+            }
+        }
+        len <<= 1;
+    }
+    if invert {
+        let scale = pow_mod(n as u64, P - 2);
+        values.iter_mut().for_each(|x| *x = mul_mod(*x, scale));
+    }
+}
+
+/// Little-endian 16 bit digits of little-endian limbs, zero-padded to `len`.
+fn to_digits(limbs: &[u64], len: usize) -> Vec<u64> {
+    let mut res: Vec<u64> = limbs
+        .iter()
+        .flat_map(|x| (0..DIGITS_PER_LIMB).map(move |i| (x >> (DIGIT_BITS * i)) & 0xffff))
+        .collect();
+    res.resize(len, 0);
+    res
+}
+
+/// Product of two limb slices, `a.len() + b.len()` limbs long, by convolution.
+pub(crate) fn mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut res = vec![0u64; a.len() + b.len()];
+    if a.is_empty() || b.is_empty() {
+        return res;
+    }
+    let n = (DIGITS_PER_LIMB * res.len()).next_power_of_two();
+    let (mut x, mut y) = (to_digits(a, n), to_digits(b, n));
+    transform(&mut x, false);
+    transform(&mut y, false);
+    x.iter_mut().zip(&y).for_each(|(x, y)| *x = mul_mod(*x, *y));
+    transform(&mut x, true);
+    let mut carry = 0u128;
+    for (i, coefficient) in x.iter().take(DIGITS_PER_LIMB * res.len()).enumerate() {
+        carry += *coefficient as u128;
+        let digit = (carry & 0xffff) as u64;
+        res[i / DIGITS_PER_LIMB] |= digit << (DIGIT_BITS * (i % DIGITS_PER_LIMB));
+        carry >>= DIGIT_BITS;
+    }
+    limbs::scrub_limbs(&mut x);
+    limbs::scrub_limbs(&mut y);
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_round_trips() {
+        let values: Vec<u64> = (0..64).map(|x| x * x + 3).collect();
+        let mut transformed = values.clone();
+        transform(&mut transformed, false);
+        assert_ne!(transformed, values);
+        transform(&mut transformed, true);
+        assert_eq!(transformed, values);
+        assert_eq!(pow_mod(G, (P - 1) / 2), P - 1);
+    }
+
+    fn limbs_of(len: usize, seed: u64) -> Vec<u64> {
+        (0..len as u64)
+            .map(|x| x.wrapping_mul(0x9e37_79b9_7f4a_7c15) ^ seed)
+            .collect()
+    }
+
+    #[test]
+    fn test_mul_matches_schoolbook() {
+        for (x, y) in [(1, 1), (1, 7), (5, 3), (100, 64), (257, 31)] {
+            let (a, b) = (limbs_of(x, 1), limbs_of(y, u64::MAX));
+            assert_eq!(mul(&a, &b), limbs::mul_limbs(&a, &b));
+        }
+        let ones = vec![u64::MAX; 200];
+        assert_eq!(mul(&ones, &ones), limbs::mul_limbs(&ones, &ones));
+        assert_eq!(mul(&[], &[1]), vec![0]);
+    }
+}
@@ -0,0 +1,143 @@
+//! Multi-threaded kernels for very large operands and large collections, enabled by the `rayon`
+//! feature.
+//!
+//! Multiplication splits the longer operand across threads and sums the shifted partial
+//! products. Parsing digits splits them in half recursively, so each half is converted on its own
+//! thread and joined with one multiplication. Formatting to digits stays sequential, as splitting
+//! it needs division by a full-width power of the radix, which the crate does not implement.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::{limbs, radix, Base256};
+use core::borrow::Borrow;
+use rayon::prelude::*;
+
+/// Size in bytes, or digits when parsing, above which work is spread across threads.
+pub(crate) const THRESHOLD: usize = 16 * 1024;
+
+/// Fewest limbs handed to a single thread, so small slices aren't split for no gain.
+const MIN_CHUNK_LIMBS: usize = 256;
+
+/// Product of two limb slices, `a.len() + b.len()` limbs long, with the longer one split into a
+/// chunk per thread.
+pub(crate) fn mul_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let chunk = long
+        .len()
+        .div_ceil(rayon::current_num_threads())
+        .max(MIN_CHUNK_LIMBS);
+    let mut parts: Vec<Vec<u64>> = long
+        .par_chunks(chunk)
+        .map(|x| limbs::mul_serial(x, short))
+        .collect();
+    let mut res = vec![0u64; a.len() + b.len()];
+    for (i, part) in parts.iter_mut().enumerate() {
+        limbs::add_shifted(&mut res, part, i * chunk);
+        limbs::scrub_limbs(part);
+    }
+    res
+}
+
+/// Value of already validated `digits`, converting each half on its own thread.
+pub(crate) fn digits_value(digits: &[u8], radix: u32) -> Base256 {
+    if digits.len() <= THRESHOLD {
+        return radix::digits_value(digits, radix);
+    }
+    let (high, low) = digits.split_at(digits.len() / 2);
+    let ((high, low), scale) = rayon::join(
+        || rayon::join(|| digits_value(high, radix), || digits_value(low, radix)),
+        || power(radix, low.len()),
+    );
+    high * &scale + &low
+}
+
+/// `radix` to the power `exp`, by repeated squaring.
+fn power(radix: u32, mut exp: usize) -> Base256 {
+    let mut res = Base256::new(vec![1]);
+    let mut base = Base256::from(radix);
+    while exp > 0 {
+        if exp % 2 == 1 {
+            res = res * &base;
+        }
+        exp /= 2;
+        if exp > 0 {
+            base = &base * &base;
+        }
+    }
+    res
+}
+
+impl Base256 {
+    /// [`WrappedSum::wrapped_sum`](crate::WrappedSum::wrapped_sum) across threads: each thread
+    /// folds a share of `values` at `byte_length`, then the partial sums are added pairwise.
+    ///
+    /// Addition modulo `2^(8 * byte_length)` is associative, so the result is the same as the
+    /// sequential sum, including for an empty collection.
+    /// ```
+    /// use byte_arithmetic::{Base256, WrappedSum};
+    /// let hashes: Vec<Base256> = (0..1000u32).map(|x| Base256::from(x * 7919)).collect();
+    /// let sequential = hashes.iter().cloned().wrapped_sum(2);
+    /// assert_eq!(Base256::wrapped_sum_par(&hashes, 2), sequential);
+    /// ```
+    pub fn wrapped_sum_par<T, I>(values: I, byte_length: usize) -> Self
+    where
+        T: Borrow<Base256>,
+        I: IntoParallelIterator<Item = T>,
+    {
+        let zero = || Base256::new(vec![0]);
+        values
+            .into_par_iter()
+            .fold(zero, |mut acc, x| {
+                acc += x.borrow();
+                acc.wrap_to(byte_length)
+            })
+            .reduce(zero, |a, b| a.wrapped_add(b, byte_length))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WrappedSum;
+
+    fn limbs_of(len: usize, seed: u64) -> Vec<u64> {
+        (0..len as u64)
+            .map(|x| x.wrapping_mul(0x9e37_79b9_7f4a_7c15) ^ seed)
+            .collect()
+    }
+
+    #[test]
+    fn test_parallel_mul_matches_schoolbook() {
+        for (x, y) in [(3000, 2500), (2100, 3), (1, 5000)] {
+            let (a, b) = (limbs_of(x, 1), limbs_of(y, u64::MAX));
+            assert_eq!(mul_limbs(&a, &b), limbs::mul_limbs(&a, &b));
+        }
+    }
+
+    #[test]
+    fn test_parallel_digits_match_sequential() {
+        let digits: Vec<u8> = (0..2 * THRESHOLD + 5).map(|x| (x * 7 % 10) as u8).collect();
+        let expected = radix::digits_value(&digits, 10).with_width(1);
+        assert_eq!(digits_value(&digits, 10).with_width(1), expected);
+    }
+
+    #[test]
+    fn test_wrapped_sum_par_matches_sequential() {
+        let hashes: Vec<Base256> = (0..10_000u64)
+            .map(|x| Base256::new(x.wrapping_mul(0x9e37_79b9_7f4a_7c15).to_be_bytes().to_vec()))
+            .collect();
+        for byte_length in [1, 4, 8, 12] {
+            let expected = hashes.iter().cloned().wrapped_sum(byte_length);
+            assert_eq!(Base256::wrapped_sum_par(hashes.clone(), byte_length), expected);
+            assert_eq!(Base256::wrapped_sum_par(&hashes, byte_length), expected);
+        }
+        assert_eq!(Base256::wrapped_sum_par(Vec::<Base256>::new(), 4), Base256::new(vec![0]));
+    }
+
+    #[test]
+    fn test_power() {
+        assert_eq!(power(10, 0), Base256::new(vec![1]));
+        assert_eq!(power(10, 19), Base256::from(10u64.pow(19)));
+        assert_eq!(power(2, 64), Base256::new(vec![1, 0, 0, 0, 0, 0, 0, 0, 0]));
+    }
+}
@@ -0,0 +1,147 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::{Base256, ParseError};
+use core::str::FromStr;
+
+/// Digit values of `s` in `radix`, reporting error indices relative to `offset`. Underscores are
+/// skipped when `separators` is set.
+fn parse_digits(
+    s: &str,
+    radix: u32,
+    offset: usize,
+    separators: bool,
+) -> Result<Vec<u8>, ParseError> {
+    let mut digits: Vec<u8> = Vec::with_capacity(s.len());
+    for (index, x) in s.char_indices() {
+        if separators && x == '_' {
+            continue;
+        }
+        match x.to_digit(radix) {
+            Some(digit) => digits.push(digit as u8),
+            None => {
+                return Err(ParseError::InvalidDigit {
+                    index: index + offset,
+                })
+            }
+        }
+    }
+    Ok(digits)
+}
+
+impl Base256 {
+    /// Parse a string of digits in `radix`, mirroring `u64::from_str_radix`. Digits above 9 are
+    /// letters of either case, and a leading `+` is accepted.
+    ///
+    /// Panics if `radix` is not in `2..=36`.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// assert_eq!(Base256::from_str_radix("zz", 36), Ok(Base256::from(1295u32)));
+    /// assert_eq!(Base256::from_str_radix("+FF", 16), Ok(Base256::new(vec![255])));
+    /// ```
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseError> {
+        if !(2..=36).contains(&radix) {
+            panic!("Radix must be in 2..=36, got {}", radix)
+        }
+        let (digits, offset) = match s.strip_prefix('+') {
+            Some(rest) => (rest, 1),
+            None => (s, 0),
+        };
+        Base256::from_radix_digits(&parse_digits(digits, radix, offset, false)?, radix)
+    }
+
+    /// Parse decimal, or hexadecimal, binary or octal with a `0x`, `0b` or `0o` prefix.
+    /// Underscores may separate digits.
+    ///
+    /// Hexadecimal keeps leading zero bytes as `from_hex` does; other radices give the minimal
+    /// representation.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// assert_eq!(Base256::parse("1_000"), Ok(Base256::from(1000u32)));
+    /// assert_eq!(Base256::parse("0xdead_beef"), Ok(Base256::from(0xdead_beefu32)));
+    /// assert_eq!(Base256::parse("0b1_0000_0000"), Ok(Base256::new(vec![1, 0])));
+    /// assert_eq!(Base256::parse("0o777"), Ok(Base256::from(511u32)));
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let (radix, offset) = match s.get(..2) {
+            Some("0x") | Some("0X") => (16, 2),
+            Some("0b") | Some("0B") => (2, 2),
+            Some("0o") | Some("0O") => (8, 2),
+            _ => (10, 0),
+        };
+        if s.starts_with('_') {
+            return Err(ParseError::InvalidDigit { index: 0 });
+        }
+        let digits = parse_digits(&s[offset..], radix, offset, true)?;
+        if radix == 16 {
+            let cleaned: String = s[offset..].chars().filter(|x| *x != '_').collect();
+            return Base256::from_hex(&cleaned);
+        }
+        Base256::from_radix_digits(&digits, radix)
+    }
+}
+
+impl FromStr for Base256 {
+    type Err = ParseError;
+
+    /// Parse a decimal string of any length, or a prefixed one as with [`Base256::parse`].
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let value: Base256 = "340282366920938463463374607431768211456".parse().unwrap();
+    /// assert_eq!(value.len(), 17);
+    /// assert_eq!(value.to_string().parse::<Base256>(), Ok(value));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Base256::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!("0".parse(), Ok(Base256::new(vec![0])));
+        assert_eq!("000256".parse(), Ok(Base256::new(vec![1, 0])));
+        assert_eq!(u64::MAX.to_string().parse(), Ok(Base256::from(u64::MAX)));
+    }
+
+    #[test]
+    fn test_parse_decimal_errors() {
+        assert_eq!("".parse::<Base256>(), Err(ParseError::Empty));
+        assert_eq!("12a".parse::<Base256>(), Err(ParseError::InvalidDigit { index: 2 }));
+        assert_eq!("-1".parse::<Base256>(), Err(ParseError::InvalidDigit { index: 0 }));
+    }
+
+    #[test]
+    fn test_from_str_radix() {
+        assert_eq!(Base256::from_str_radix("1010", 2), Ok(Base256::new(vec![10])));
+        assert_eq!(Base256::from_str_radix("0", 7), Ok(Base256::new(vec![0])));
+        assert_eq!(Base256::from_str_radix("+", 10), Err(ParseError::Empty));
+        assert_eq!(Base256::from_str_radix("1_0", 10), Err(ParseError::InvalidDigit { index: 1 }));
+        assert_eq!(Base256::from_str_radix("+19", 9), Err(ParseError::InvalidDigit { index: 2 }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_str_radix_out_of_range() {
+        let _ = Base256::from_str_radix("1", 37);
+    }
+
+    #[test]
+    fn test_parse_prefixes() {
+        assert_eq!(Base256::parse("0X0001"), Ok(Base256::new(vec![0, 1])));
+        assert_eq!(Base256::parse("0x_f"), Ok(Base256::new(vec![0xf])));
+        assert_eq!(Base256::parse("0B11"), Ok(Base256::new(vec![3])));
+        assert_eq!(Base256::parse("007"), Ok(Base256::new(vec![7])));
+    }
+
+    #[test]
+    fn test_parse_prefix_errors() {
+        assert_eq!(Base256::parse("0x"), Err(ParseError::Empty));
+        assert_eq!(Base256::parse("0b__"), Err(ParseError::Empty));
+        assert_eq!(Base256::parse("0b102"), Err(ParseError::InvalidDigit { index: 4 }));
+        assert_eq!(Base256::parse("0o8"), Err(ParseError::InvalidDigit { index: 2 }));
+        assert_eq!(Base256::parse("_1"), Err(ParseError::InvalidDigit { index: 0 }));
+    }
+}
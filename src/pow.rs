@@ -0,0 +1,88 @@
+use alloc::vec;
+use crate::{cmp_numeric, Base256};
+use core::cmp::Ordering;
+
+impl Base256 {
+    /// Number of zero bits before the first set bit, over every stored byte, so a 32byte hash
+    /// of all zeros has 256.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// assert_eq!(Base256::new(vec![0, 0b0001_0000, 0xff]).leading_zero_bits(), 11);
+    /// assert_eq!(Base256::new(vec![0, 0]).leading_zero_bits(), 16);
+    /// ```
+    pub fn leading_zero_bits(&self) -> usize {
+        match self.iter().position(|x| *x != 0) {
+            Some(index) => 8 * index + self[index].leading_zeros() as usize,
+            None => 8 * self.len(),
+        }
+    }
+
+    /// Whether `self`, read as a hash, is numerically at or below `target`, as proof-of-work
+    /// checks require. Leading zero bytes are ignored on both sides.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let target = Base256::target_for_bits(12, 4);
+    /// assert!(Base256::new(vec![0x00, 0x0f, 0xff, 0xff]).meets_target(&target));
+    /// assert!(!Base256::new(vec![0x00, 0x10, 0x00, 0x00]).meets_target(&target));
+    /// ```
+    pub fn meets_target(&self, target: &Base256) -> bool {
+        cmp_numeric(self, target) != Ordering::Greater
+    }
+
+    /// The `byte_length` byte target met exactly by hashes with at least `bits` leading zero
+    /// bits: `bits` zeros followed by ones.
+    ///
+    /// Panics if `bits` is more than `8 * byte_length`.
+    pub fn target_for_bits(bits: usize, byte_length: usize) -> Self {
+        if bits > 8 * byte_length {
+            panic!("Difficulty exceeds the target width")
+        }
+        let mut res = vec![0xff; byte_length];
+        res[..bits / 8].fill(0);
+        if bits < 8 * byte_length {
+            res[bits / 8] = 0xff >> (bits % 8);
+        }
+        Base256::new(res)
+    }
+
+    /// Difficulty of `self` read as a target, in bits: the whole number of halvings of the
+    /// hash space it takes to reach it, and the inverse of [`Base256::target_for_bits`].
+    ///
+    /// A target of `byte_length` bytes is met by a fraction `(target + 1) / 2^(8 * len)` of
+    /// hashes, whose `-log2`, rounded down, is the target's leading zero bits.
+    pub fn difficulty_bits(&self) -> usize {
+        self.leading_zero_bits()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_round_trips() {
+        for bits in 0..=24 {
+            let target = Base256::target_for_bits(bits, 3);
+            assert_eq!(target.len(), 3);
+            assert_eq!(target.difficulty_bits(), bits);
+            let hash = target.inc();
+            assert!(target.meets_target(&target));
+            assert!(!hash.meets_target(&target));
+        }
+    }
+
+    #[test]
+    fn test_meets_target_is_numeric() {
+        let target = Base256::new(vec![0x10]);
+        assert!(Base256::new(vec![0, 0, 0x0f]).meets_target(&target));
+        assert!(!Base256::new(vec![0x01, 0x00]).meets_target(&target));
+        assert!(Base256::empty().meets_target(&target));
+        assert_eq!(Base256::empty().leading_zero_bits(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Difficulty exceeds the target width")]
+    fn test_target_too_hard() {
+        Base256::target_for_bits(17, 2);
+    }
+}
@@ -0,0 +1,133 @@
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "rayon")]
+use crate::parallel;
+use crate::{Base256, ParseError};
+
+/// Largest power of `radix` that fits in a u64, and its exponent.
+fn digit_chunk(radix: u32) -> (u64, usize) {
+    let mut power = radix as u64;
+    let mut digits = 1;
+    while let Some(next) = power.checked_mul(radix as u64) {
+        power = next;
+        digits += 1;
+    }
+    (power, digits)
+}
+
+fn check_radix(radix: u32) {
+    if !(2..=256).contains(&radix) {
+        panic!("Radix must be in 2..=256, got {}", radix)
+    }
+}
+
+impl Base256 {
+    /// Digits of the value in `radix`, most significant first. Zero is `[0]`.
+    ///
+    /// Panics if `radix` is not in `2..=256`.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// assert_eq!(Base256::from(1000u32).to_radix_digits(10), vec![1, 0, 0, 0]);
+    /// assert_eq!(Base256::from(255u8).to_radix_digits(16), vec![15, 15]);
+    /// ```
+    pub fn to_radix_digits(&self, radix: u32) -> Vec<u8> {
+        check_radix(radix);
+        if radix == 256 {
+            return self.clone().with_width(1).into();
+        }
+        let (chunk, chunk_digits) = digit_chunk(radix);
+        let mut value = Base256::new(self.significant_bytes().to_vec());
+        let mut digits: Vec<u8> = Vec::new();
+        while !value.is_empty() {
+            let (quotient, mut remainder) = value.div_rem_u64(chunk);
+            value = Base256::new(quotient.significant_bytes().to_vec());
+            for _ in 0..chunk_digits {
+                digits.push((remainder % radix as u64) as u8);
+                remainder /= radix as u64;
+                if value.is_empty() && remainder == 0 {
+                    break;
+                }
+            }
+        }
+        if digits.is_empty() {
+            digits.push(0);
+        }
+        digits.reverse();
+        digits
+    }
+
+    /// Value of `digits` in `radix`, most significant first.
+    ///
+    /// Errors if there are no digits or a digit is not below `radix`. Panics if `radix` is not in
+    /// `2..=256`.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// assert_eq!(Base256::from_radix_digits(&[1, 0, 0, 0], 10), Ok(Base256::from(1000u32)));
+    /// assert!(Base256::from_radix_digits(&[1, 10], 10).is_err());
+    /// ```
+    pub fn from_radix_digits(digits: &[u8], radix: u32) -> Result<Self, ParseError> {
+        check_radix(radix);
+        if digits.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        if let Some(index) = digits.iter().position(|x| *x as u32 >= radix) {
+            return Err(ParseError::InvalidDigit { index });
+        }
+        if radix == 256 {
+            return Ok(Base256::new(digits.to_vec()).with_width(1));
+        }
+        #[cfg(feature = "rayon")]
+        if digits.len() > parallel::THRESHOLD {
+            return Ok(parallel::digits_value(digits, radix).with_width(1));
+        }
+        Ok(digits_value(digits, radix).with_width(1))
+    }
+}
+
+/// Value of already validated `digits`, folding in a u64's worth of digits at a time.
+pub(crate) fn digits_value(digits: &[u8], radix: u32) -> Base256 {
+    let (_, chunk_digits) = digit_chunk(radix);
+    let mut value = Base256::new(vec![0]);
+    for chunk in digits.chunks(chunk_digits) {
+        let scale = (radix as u64).pow(chunk.len() as u32);
+        let part = chunk.iter().fold(0u64, |acc, x| acc * radix as u64 + *x as u64);
+        value = value.mul_u64(scale) + Base256::from(part);
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_radix_round_trip() {
+        let value = Base256::new(vec![0xde, 0xad, 0xbe, 0xef, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab]);
+        for radix in [2, 3, 10, 36, 58, 62, 85, 255, 256] {
+            let digits = value.to_radix_digits(radix);
+            assert!(digits.iter().all(|x| (*x as u32) < radix));
+            assert_eq!(Base256::from_radix_digits(&digits, radix), Ok(value.clone()));
+        }
+    }
+
+    #[test]
+    fn test_radix_zero() {
+        assert_eq!(Base256::new(vec![0, 0]).to_radix_digits(85), vec![0]);
+        assert_eq!(Base256::empty().to_radix_digits(256), vec![0]);
+        assert_eq!(Base256::from_radix_digits(&[0, 0], 62), Ok(Base256::new(vec![0])));
+        assert_eq!(Base256::from_radix_digits(&[], 62), Err(ParseError::Empty));
+    }
+
+    #[test]
+    fn test_radix_matches_u128() {
+        let n = 0x0123_4567_89ab_cdef_0011_2233_4455_6677u128;
+        let digits: Vec<u8> = format!("{:o}", n).bytes().map(|x| x - b'0').collect();
+        assert_eq!(Base256::from(n).to_radix_digits(8), digits);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_radix_out_of_range() {
+        Base256::new(vec![1]).to_radix_digits(257);
+    }
+}
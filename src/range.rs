@@ -0,0 +1,133 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::{cmp_numeric, Base256};
+use core::cmp::Ordering;
+
+/// Half-open range of Base256 values, `start..end`, compared numerically.
+///
+/// Used to walk or pre-split a keyspace, i.e. dividing the space of 32byte hashes into shards
+/// for parallel scans.
+/// ```
+/// use byte_arithmetic::{Base256, Base256Range};
+/// let range = Base256Range::new(Base256::new(vec![0, 0]), Base256::new(vec![1, 0]));
+/// assert_eq!(
+///     range.partition_points(4),
+///     vec![
+///         Base256::new(vec![0, 0]),
+///         Base256::new(vec![0, 64]),
+///         Base256::new(vec![0, 128]),
+///         Base256::new(vec![0, 192]),
+///     ]
+/// );
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+pub struct Base256Range {
+    start: Base256,
+    end: Base256,
+}
+
+impl Base256Range {
+    pub fn new(start: Base256, end: Base256) -> Self {
+        Base256Range { start, end }
+    }
+
+    pub fn start(&self) -> &Base256 {
+        &self.start
+    }
+
+    pub fn end(&self) -> &Base256 {
+        &self.end
+    }
+
+    pub fn is_empty(&self) -> bool {
+        cmp_numeric(&self.start, &self.end) != Ordering::Less
+    }
+
+    /// Iterate from `start` towards `end` in increments of `step`.
+    ///
+    /// Panics if `step` is zero.
+    pub fn step_by(self, step: Base256) -> StepBy {
+        if step.iter().all(|x| *x == 0) {
+            panic!("Step must be non-zero")
+        }
+        StepBy {
+            next: self.start,
+            end: self.end,
+            step,
+        }
+    }
+
+    /// `n` evenly spaced boundaries, starting at `start`, each beginning one of `n` sub-ranges.
+    ///
+    /// Boundaries are returned at the width of the wider endpoint. Panics if `n` is zero.
+    pub fn partition_points(&self, n: usize) -> Vec<Base256> {
+        if n == 0 {
+            panic!("Cannot partition into zero ranges")
+        }
+        let width = core::cmp::max(self.start.len(), self.end.len());
+        if self.is_empty() {
+            return vec![self.start.clone().with_width(width); n];
+        }
+        let span = &self.end - &self.start;
+        (0..n as u64)
+            .map(|i| {
+                let (offset, _) = span.mul_u64(i).div_rem_u64(n as u64);
+                (&self.start + offset.with_width(width)).with_width(width)
+            })
+            .collect()
+    }
+}
+
+/// Iterator returned by [`Base256Range::step_by`].
+#[derive(Debug, Clone)]
+pub struct StepBy {
+    next: Base256,
+    end: Base256,
+    step: Base256,
+}
+
+impl Iterator for StepBy {
+    type Item = Base256;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if cmp_numeric(&self.next, &self.end) != Ordering::Less {
+            return None;
+        }
+        let following = &self.next + &self.step;
+        Some(core::mem::replace(&mut self.next, following))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_by() {
+        let range = Base256Range::new(Base256::new(vec![0, 250]), Base256::new(vec![1, 4]));
+        assert_eq!(
+            range.step_by(Base256::new(vec![5])).collect::<Vec<_>>(),
+            vec![Base256::new(vec![0, 250]), Base256::new(vec![0, 255])]
+        );
+    }
+
+    #[test]
+    fn test_partition_points_uneven() {
+        let range = Base256Range::new(Base256::new(vec![10]), Base256::new(vec![20]));
+        assert_eq!(
+            range.partition_points(3),
+            vec![
+                Base256::new(vec![10]),
+                Base256::new(vec![13]),
+                Base256::new(vec![16]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_partition_points_empty() {
+        let range = Base256Range::new(Base256::new(vec![5]), Base256::new(vec![0, 5]));
+        assert!(range.is_empty());
+        assert_eq!(range.partition_points(2), vec![Base256::new(vec![0, 5]); 2]);
+    }
+}
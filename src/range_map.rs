@@ -0,0 +1,373 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::{Base256, Endpoint, Interval};
+
+/// Map from non-overlapping arcs of a `byte_length` byte ring to values, i.e. a shard map or the
+/// key ranges a routing table delegates to each peer.
+///
+/// Inserting over existing entries overwrites the overlap, splitting or trimming those entries
+/// so they keep their value on whatever is left. Entries are returned as `[start, end)`, with
+/// `start == end` for one covering the whole ring.
+/// ```
+/// use byte_arithmetic::{Base256, Interval, RangeMap};
+/// let arc = |x: u8, y: u8| Interval::new(Base256::new(vec![x]), Base256::new(vec![y]), 1);
+/// let mut shards = RangeMap::new(1);
+/// shards.insert(arc(0xc0, 0x40), "a");
+/// shards.insert(arc(0xf0, 0x10), "b");
+/// assert_eq!(shards.get(&Base256::new(vec![0x20])), Some(&"a"));
+/// assert_eq!(shards.get(&Base256::new(vec![0xff])), Some(&"b"));
+/// assert_eq!(shards.get(&Base256::new(vec![0x80])), None);
+/// let entries: Vec<(Interval, &str)> = shards.iter().map(|(x, y)| (x, *y)).collect();
+/// assert_eq!(entries, [(arc(0x10, 0x40), "a"), (arc(0xc0, 0xf0), "a"), (arc(0xf0, 0x10), "b")]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeMap<V> {
+    byte_length: usize,
+    /// Entries as `start -> (end, value)`. At most one wraps through zero, and it has the
+    /// highest start.
+    entries: BTreeMap<Base256, (Base256, V)>,
+}
+
+impl<V> RangeMap<V> {
+    pub fn new(byte_length: usize) -> Self {
+        RangeMap {
+            byte_length,
+            entries: BTreeMap::new(),
+        }
+    }
+
+    pub fn byte_length(&self) -> usize {
+        self.byte_length
+    }
+
+    /// Number of entries, after any splits.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The value whose arc contains `key`, wrapped to the ring's width.
+    pub fn get(&self, key: &Base256) -> Option<&V> {
+        self.get_entry(key).map(|(_, value)| value)
+    }
+
+    /// The arc containing `key` and its value.
+    pub fn get_entry(&self, key: &Base256) -> Option<(Interval, &V)> {
+        let key = Base256::new(key.wrapped_bytes(self.byte_length));
+        let start = self.start_containing(&key)?;
+        let (end, value) = &self.entries[start];
+        Some((
+            Interval::new(start.clone(), end.clone(), self.byte_length),
+            value,
+        ))
+    }
+
+    /// Entries sharing at least one ID with `interval`, clockwise from its start. Panics if
+    /// `interval` is on a ring of a different width.
+    pub fn overlapping(&self, interval: &Interval) -> Vec<(Interval, &V)> {
+        match self.arc_of(interval) {
+            Some(arc) => self
+                .overlapping_starts(&arc)
+                .into_iter()
+                .map(|start| {
+                    let (end, value) = &self.entries[&start];
+                    (Interval::new(start, end.clone(), self.byte_length), value)
+                })
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Entries and their values in order of start.
+    pub fn iter(&self) -> impl Iterator<Item = (Interval, &V)> {
+        self.entries.iter().map(|(start, (end, value))| {
+            (
+                Interval::new(start.clone(), end.clone(), self.byte_length),
+                value,
+            )
+        })
+    }
+
+    /// The start of the entry containing `key`, which is already at the ring's width.
+    fn start_containing(&self, key: &Base256) -> Option<&Base256> {
+        // Only the wrapping entry, which has the highest start, can hold keys below every start.
+        let (start, (end, _)) = self
+            .entries
+            .range(..=key.clone())
+            .next_back()
+            .or_else(|| self.entries.last_key_value())?;
+        let arc = Arc {
+            start: start.clone(),
+            end: end.clone(),
+        };
+        if arc.contains(key, self.byte_length) {
+            Some(start)
+        } else {
+            None
+        }
+    }
+
+    /// Starts of the entries overlapping `arc`: the one containing its start, then those
+    /// starting on it.
+    fn overlapping_starts(&self, arc: &Arc) -> Vec<Base256> {
+        let mut starts: Vec<Base256> = self
+            .start_containing(&arc.start)
+            .into_iter()
+            .cloned()
+            .collect();
+        let on_arc: Vec<&Base256> = if arc.is_full() {
+            self.entries
+                .range(arc.start.clone()..)
+                .chain(self.entries.range(..arc.start.clone()))
+                .map(|x| x.0)
+                .collect()
+        } else if arc.start < arc.end {
+            self.entries
+                .range(arc.start.clone()..arc.end.clone())
+                .map(|x| x.0)
+                .collect()
+        } else {
+            self.entries
+                .range(arc.start.clone()..)
+                .chain(self.entries.range(..arc.end.clone()))
+                .map(|x| x.0)
+                .collect()
+        };
+        for start in on_arc {
+            if starts.first() != Some(start) {
+                starts.push(start.clone());
+            }
+        }
+        starts
+    }
+
+    /// The interval as a half-open arc on this ring, or `None` if it holds no IDs.
+    fn arc_of(&self, interval: &Interval) -> Option<Arc> {
+        if interval.byte_length() != self.byte_length {
+            panic!("Interval is on a ring of a different width")
+        }
+        if interval.is_empty() {
+            return None;
+        }
+        let next = |x: &Base256| x.wrapping_inc(self.byte_length);
+        let (start, end) = (interval.start(), interval.end());
+        // `start == end` is the whole ring, less that one ID when both ends are open.
+        if start == end {
+            let start = match (interval.start_endpoint(), interval.end_endpoint()) {
+                (Endpoint::Open, Endpoint::Open) => next(start),
+                _ => start.clone(),
+            };
+            return Some(Arc {
+                start,
+                end: end.clone(),
+            });
+        }
+        let start = match interval.start_endpoint() {
+            Endpoint::Closed => start.clone(),
+            Endpoint::Open => next(start),
+        };
+        let end = match interval.end_endpoint() {
+            Endpoint::Closed => next(end),
+            Endpoint::Open => end.clone(),
+        };
+        Some(Arc { start, end })
+    }
+}
+
+impl<V: Clone> RangeMap<V> {
+    /// Map every ID in `interval` to `value`, overwriting the overlap with existing entries.
+    /// Panics if `interval` is on a ring of a different width.
+    pub fn insert(&mut self, interval: Interval, value: V) {
+        if let Some(arc) = self.arc_of(&interval) {
+            self.clear(&arc);
+            self.entries.insert(arc.start, (arc.end, value));
+        }
+    }
+
+    /// Unmap every ID in `interval`, trimming or splitting the entries it overlaps.
+    pub fn remove(&mut self, interval: &Interval) {
+        if let Some(arc) = self.arc_of(interval) {
+            self.clear(&arc);
+        }
+    }
+
+    fn clear(&mut self, arc: &Arc) {
+        for start in self.overlapping_starts(arc) {
+            if let Some((end, value)) = self.entries.remove(&start) {
+                let entry = Arc { start, end };
+                for rest in entry.difference(arc, self.byte_length) {
+                    self.entries.insert(rest.start, (rest.end, value.clone()));
+                }
+            }
+        }
+    }
+}
+
+/// Non-empty half-open arc `[start, end)` of IDs at the ring's width, the whole ring when
+/// `start == end`.
+#[derive(Debug, Clone, PartialEq)]
+struct Arc {
+    start: Base256,
+    end: Base256,
+}
+
+impl Arc {
+    fn is_full(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Number of IDs on the arc, one byte wider than the ring so the whole ring fits.
+    fn len(&self, byte_length: usize) -> Base256 {
+        if self.is_full() {
+            ring_size(byte_length)
+        } else {
+            offset(&self.start, &self.end, byte_length)
+        }
+    }
+
+    fn contains(&self, id: &Base256, byte_length: usize) -> bool {
+        self.is_full() || offset(&self.start, id, byte_length) < self.len(byte_length)
+    }
+
+    /// Arc of `len` IDs from `start`.
+    fn from_len(start: Base256, len: &Base256, byte_length: usize) -> Self {
+        let end = Base256::new((&start + len).wrapped_bytes(byte_length));
+        Arc { start, end }
+    }
+
+    /// IDs on both arcs, as at most two arcs.
+    ///
+    /// Each piece begins at the start of one arc lying on the other, and runs until either arc
+    /// ends.
+    fn intersect(&self, other: &Arc, byte_length: usize) -> Vec<Arc> {
+        if self.is_full() {
+            return vec![other.clone()];
+        }
+        if other.is_full() {
+            return vec![self.clone()];
+        }
+        let mut pieces = Vec::with_capacity(2);
+        for (x, y) in [(self, other), (other, self)] {
+            if !y.contains(&x.start, byte_length)
+                || pieces.first().map(|p: &Arc| &p.start) == Some(&x.start)
+            {
+                continue;
+            }
+            let len = x
+                .len(byte_length)
+                .min(offset(&x.start, &y.end, byte_length));
+            pieces.push(Arc::from_len(x.start.clone(), &len, byte_length));
+        }
+        pieces
+    }
+
+    /// IDs on `self` but not `other`, as at most two arcs.
+    fn difference(&self, other: &Arc, byte_length: usize) -> Vec<Arc> {
+        if other.is_full() {
+            return Vec::new();
+        }
+        let complement = Arc {
+            start: other.end.clone(),
+            end: other.start.clone(),
+        };
+        self.intersect(&complement, byte_length)
+    }
+}
+
+/// Clockwise distance from `from` to `to`, one byte wider than the ring.
+fn offset(from: &Base256, to: &Base256, byte_length: usize) -> Base256 {
+    from.ring_distance(to, byte_length)
+        .with_width(byte_length + 1)
+}
+
+/// `2^(8 * byte_length)`, the number of IDs on the ring.
+fn ring_size(byte_length: usize) -> Base256 {
+    let mut res = vec![0; byte_length + 1];
+    res[0] = 1;
+    Base256::new(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arc(start: u8, end: u8) -> Interval {
+        Interval::new(Base256::new(vec![start]), Base256::new(vec![end]), 1)
+    }
+
+    /// Value for every ID, by brute force over the one byte ring.
+    fn table(map: &RangeMap<u8>) -> Vec<Option<u8>> {
+        (0..=255u8)
+            .map(|x| map.get(&Base256::new(vec![x])).copied())
+            .collect()
+    }
+
+    #[test]
+    fn test_insert_matches_brute_force() {
+        let inserts = [
+            (arc(10, 20), 1),
+            (arc(250, 5), 2),
+            (arc(15, 12), 3),
+            (arc(0, 0), 4),
+            (
+                arc(30, 40).with_endpoints(Endpoint::Open, Endpoint::Closed),
+                5,
+            ),
+            (arc(35, 36), 6),
+            (arc(200, 100), 7),
+        ];
+        let mut map = RangeMap::new(1);
+        let mut expected = vec![None; 256];
+        for (interval, value) in inserts {
+            for id in 0..=255u8 {
+                if interval.contains(&Base256::new(vec![id])) {
+                    expected[id as usize] = Some(value);
+                }
+            }
+            map.insert(interval, value);
+            assert_eq!(table(&map), expected);
+            let total = map.iter().count();
+            assert_eq!(total, map.len());
+        }
+    }
+
+    #[test]
+    fn test_remove_splits_entry() {
+        let mut map = RangeMap::new(1);
+        map.insert(arc(0, 0), 1u8);
+        map.remove(&arc(10, 20));
+        assert_eq!(map.len(), 1);
+        assert_eq!(
+            map.get_entry(&Base256::new(vec![30])),
+            Some((arc(20, 10), &1))
+        );
+        map.remove(&arc(100, 110));
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&Base256::new(vec![15])), None);
+        assert_eq!(map.get(&Base256::new(vec![5])), Some(&1));
+    }
+
+    #[test]
+    fn test_overlapping() {
+        let mut map = RangeMap::new(1);
+        map.insert(arc(10, 20), 1u8);
+        map.insert(arc(30, 40), 2);
+        map.insert(arc(240, 5), 3);
+        let values =
+            |x: Interval| -> Vec<u8> { map.overlapping(&x).into_iter().map(|x| *x.1).collect() };
+        assert_eq!(values(arc(15, 35)), vec![1, 2]);
+        assert_eq!(values(arc(35, 12)), vec![2, 3, 1]);
+        assert_eq!(values(arc(20, 30)), Vec::<u8>::new());
+        assert_eq!(values(arc(250, 250)), vec![3, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Interval is on a ring of a different width")]
+    fn test_width_mismatch() {
+        RangeMap::new(2).insert(arc(1, 2), 1u8);
+    }
+}
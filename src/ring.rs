@@ -0,0 +1,301 @@
+use crate::Base256;
+
+/// Which way round the ring a distance was measured, from `self` towards `other`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum RingDirection {
+    /// Increasing IDs, wrapping from the maximum to zero.
+    Clockwise,
+    CounterClockwise,
+}
+
+/// Add one to big-endian `bytes` in place, returning whether it carried out of the top byte.
+fn increment(bytes: &mut [u8]) -> bool {
+    for byte in bytes.iter_mut().rev() {
+        let (res, carry) = byte.overflowing_add(1);
+        *byte = res;
+        if !carry {
+            return false;
+        }
+    }
+    true
+}
+
+/// Subtract one from big-endian `bytes` in place, returning whether it borrowed past the top.
+fn decrement(bytes: &mut [u8]) -> bool {
+    for byte in bytes.iter_mut().rev() {
+        let (res, borrow) = byte.overflowing_sub(1);
+        *byte = res;
+        if !borrow {
+            return false;
+        }
+    }
+    true
+}
+
+impl Base256 {
+    /// `self + 1`, keeping the width of `self` unless every byte carries.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// assert_eq!(Base256::new(vec![0, 0xff]).inc(), Base256::new(vec![1, 0]));
+    /// assert_eq!(Base256::new(vec![0xff]).inc(), Base256::new(vec![1, 0]));
+    /// ```
+    pub fn inc(&self) -> Self {
+        let mut res = self.inner.clone();
+        if increment(&mut res) {
+            res.insert(0, 1);
+        }
+        Base256::new(res)
+    }
+
+    /// `self - 1` at the width of `self`, or `None` if `self` is zero.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// assert_eq!(Base256::new(vec![1, 0]).dec(), Some(Base256::new(vec![0, 0xff])));
+    /// assert_eq!(Base256::new(vec![0, 0]).dec(), None);
+    /// ```
+    pub fn dec(&self) -> Option<Self> {
+        let mut res = self.inner.clone();
+        match decrement(&mut res) {
+            true => None,
+            false => Some(Base256::new(res)),
+        }
+    }
+
+    /// The ID after `self` on a ring of `byte_length` bytes, wrapping from the maximum to zero.
+    ///
+    /// Turns an inclusive end into an exclusive one, i.e. `[start, end]` into `[start, end + 1)`.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// assert_eq!(Base256::new(vec![0xff, 0xff]).wrapping_inc(2), Base256::new(vec![0, 0]));
+    /// assert_eq!(Base256::new(vec![0, 0]).wrapping_dec(2), Base256::new(vec![0xff, 0xff]));
+    /// ```
+    pub fn wrapping_inc(&self, byte_length: usize) -> Self {
+        let mut res = self.wrapped_bytes(byte_length);
+        increment(&mut res);
+        Base256::new(res)
+    }
+
+    /// The ID before `self` on a ring of `byte_length` bytes, wrapping from zero to the maximum.
+    pub fn wrapping_dec(&self, byte_length: usize) -> Self {
+        let mut res = self.wrapped_bytes(byte_length);
+        decrement(&mut res);
+        Base256::new(res)
+    }
+
+    /// Clockwise distance from `self` to `other` on a ring of `byte_length` bytes, i.e.
+    /// `(other - self) mod 2^(8 * byte_length)`, as Chord measures successor distance.
+    ///
+    /// Both operands are first wrapped to `byte_length` bytes, and the result is always exactly
+    /// that wide. The distance back from `other` to `self` is the complement, except at zero.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let node = Base256::new(vec![0xf0]);
+    /// assert_eq!(node.ring_distance(&Base256::new(vec![0x10]), 1), Base256::new(vec![0x20]));
+    /// assert_eq!(Base256::new(vec![0x10]).ring_distance(&node, 1), Base256::new(vec![0xe0]));
+    /// assert_eq!(node.ring_distance(&node, 2), Base256::new(vec![0, 0]));
+    /// ```
+    pub fn ring_distance(&self, other: &Self, byte_length: usize) -> Self {
+        let mut res = other.wrapped_bytes(byte_length);
+        let start = self.wrapped_bytes(byte_length);
+        let mut borrow = 0i16;
+        for (x, y) in res.iter_mut().zip(start).rev() {
+            let mut acc = *x as i16 - y as i16 - borrow;
+            borrow = 0;
+            if acc < 0 {
+                acc += 256;
+                borrow = 1;
+            }
+            *x = acc as u8;
+        }
+        Base256::new(res)
+    }
+
+    /// The ID halfway along the clockwise arc from `self` to `other` on a ring of `byte_length`
+    /// bytes, rounding towards `self`. Equal IDs span the whole ring, so the midpoint is the
+    /// opposite ID.
+    ///
+    /// Splitting a node's range for a joining node at this point gives each half of the keys.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let node = Base256::new(vec![0xf0]);
+    /// assert_eq!(node.ring_midpoint(&Base256::new(vec![0x10]), 1), Base256::new(vec![0x00]));
+    /// assert_eq!(node.ring_midpoint(&Base256::new(vec![0xf3]), 1), Base256::new(vec![0xf1]));
+    /// assert_eq!(node.ring_midpoint(&node, 1), Base256::new(vec![0x70]));
+    /// ```
+    pub fn ring_midpoint(&self, other: &Self, byte_length: usize) -> Self {
+        let distance = self.ring_distance(other, byte_length);
+        let mut half = distance.div_rem_u64(2).0;
+        if byte_length > 0 && distance.iter().all(|x| *x == 0) {
+            half.inner[0] = 0x80;
+        }
+        (self + half).wrap_to(byte_length).with_width(byte_length)
+    }
+
+    /// Weighted average of `a` and `b` along the clockwise arc from `a` to `b` on a ring of
+    /// `byte_length` bytes, rounding towards `a`. The heavier end pulls the point towards
+    /// itself, and equal weights give [`Base256::ring_midpoint`].
+    ///
+    /// The point lies `weight_b / (weight_a + weight_b)` of the way round from `a`. Weights
+    /// whose sum overflows a `u64` are halved until it fits. Panics if both weights are zero.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let (a, b) = (Base256::new(vec![0xf0]), Base256::new(vec![0x30]));
+    /// assert_eq!(Base256::weighted_point(&a, &b, 1, 1, 1), Base256::new(vec![0x10]));
+    /// assert_eq!(Base256::weighted_point(&a, &b, 3, 1, 1), Base256::new(vec![0x00]));
+    /// assert_eq!(Base256::weighted_point(&a, &b, 0, 1, 1), b);
+    /// ```
+    pub fn weighted_point(
+        a: &Self,
+        b: &Self,
+        weight_a: u64,
+        weight_b: u64,
+        byte_length: usize,
+    ) -> Self {
+        if weight_a == 0 && weight_b == 0 {
+            panic!("Weights must not both be zero")
+        }
+        let (mut weight_a, mut weight_b) = (weight_a, weight_b);
+        while weight_a.checked_add(weight_b).is_none() {
+            weight_a >>= 1;
+            weight_b >>= 1;
+        }
+        let mut distance = a.ring_distance(b, byte_length).with_width(byte_length + 1);
+        if distance.iter().all(|x| *x == 0) {
+            distance.inner[0] = 1;
+        }
+        let (offset, _) = distance.mul_u64(weight_b).div_rem_u64(weight_a + weight_b);
+        Base256::new((a + offset).wrapped_bytes(byte_length))
+    }
+
+    /// The shorter way from `self` to `other` on a ring of `byte_length` bytes, and which
+    /// direction it runs. Ties, including equal IDs and points exactly half the ring apart, go
+    /// clockwise.
+    /// ```
+    /// use byte_arithmetic::{Base256, RingDirection};
+    /// let node = Base256::new(vec![0x10]);
+    /// assert_eq!(
+    ///     node.ring_distance_min(&Base256::new(vec![0xf0]), 1),
+    ///     (Base256::new(vec![0x20]), RingDirection::CounterClockwise)
+    /// );
+    /// assert_eq!(
+    ///     node.ring_distance_min(&Base256::new(vec![0x30]), 1),
+    ///     (Base256::new(vec![0x20]), RingDirection::Clockwise)
+    /// );
+    /// ```
+    pub fn ring_distance_min(&self, other: &Self, byte_length: usize) -> (Self, RingDirection) {
+        let clockwise = self.ring_distance(other, byte_length);
+        let counter_clockwise = other.ring_distance(self, byte_length);
+        // Both are exactly `byte_length` bytes, so byte order is numeric order.
+        if counter_clockwise < clockwise {
+            (counter_clockwise, RingDirection::CounterClockwise)
+        } else {
+            (clockwise, RingDirection::Clockwise)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_ring_distance_matches_u16() {
+        for (a, b) in [(0u16, 0u16), (1, 0), (0, 1), (0xfff0, 0x0010), (0x1234, 0xabcd)] {
+            let distance = Base256::from(a).ring_distance(&Base256::from(b), 2);
+            assert_eq!(u64::try_from(&distance), Ok(b.wrapping_sub(a) as u64));
+            assert_eq!(distance.len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_ring_distance_wraps_operands() {
+        let long = Base256::new(vec![0xff, 0x00, 0x05]);
+        assert_eq!(
+            Base256::new(vec![3]).ring_distance(&long, 2),
+            Base256::new(vec![0, 2])
+        );
+        assert_eq!(long.ring_distance(&long, 0), Base256::empty());
+    }
+
+    #[test]
+    fn test_inc_dec_match_u64() {
+        for value in [0u64, 1, 0xff, 0x100, 0xffff, u64::MAX - 1] {
+            let base = Base256::new(value.to_be_bytes().to_vec());
+            assert_eq!(u64::try_from(&base.inc()), Ok(value + 1));
+            assert_eq!(base.inc().len(), 8);
+            assert_eq!(base.inc().dec(), Some(base.clone()));
+            assert_eq!(base.wrapping_inc(1), Base256::new(vec![(value as u8).wrapping_add(1)]));
+            assert_eq!(base.wrapping_dec(1), Base256::new(vec![(value as u8).wrapping_sub(1)]));
+        }
+        assert_eq!(Base256::empty().inc(), Base256::new(vec![1]));
+        assert_eq!(Base256::empty().dec(), None);
+        assert_eq!(Base256::new(vec![4]).wrapping_inc(0), Base256::empty());
+    }
+
+    #[test]
+    fn test_ring_midpoint_matches_u16() {
+        for (a, b) in [(0u16, 0xffffu16), (0xfff0, 0x0010), (0x1234, 0x1235), (0x8000, 0x0000)] {
+            let midpoint = Base256::from(a).ring_midpoint(&Base256::from(b), 2);
+            assert_eq!(u64::try_from(&midpoint), Ok(a.wrapping_add(b.wrapping_sub(a) / 2) as u64));
+            assert_eq!(midpoint.len(), 2);
+        }
+        let node = Base256::new(vec![0, 0x10]);
+        assert_eq!(node.ring_midpoint(&node, 2), Base256::new(vec![0x80, 0x10]));
+        assert_eq!(Base256::empty().ring_midpoint(&Base256::empty(), 0), Base256::empty());
+    }
+
+    #[test]
+    fn test_weighted_point_matches_u16() {
+        for (a, b, weight_a, weight_b) in [
+            (0xfff0u16, 0x0010u16, 1u64, 3u64),
+            (0x1234, 0x1234, 1, 1),
+            (0, 0xffff, 7, 2),
+            (0x8000, 0x7fff, 5, 0),
+        ] {
+            let point = Base256::weighted_point(
+                &Base256::from(a),
+                &Base256::from(b),
+                weight_a,
+                weight_b,
+                2,
+            );
+            let mut distance = b.wrapping_sub(a) as u64;
+            if distance == 0 {
+                distance = 1 << 16;
+            }
+            let offset = distance * weight_b / (weight_a + weight_b);
+            assert_eq!(u64::try_from(&point), Ok(a.wrapping_add(offset as u16) as u64));
+            assert_eq!(point.len(), 2);
+        }
+        let (a, b) = (Base256::new(vec![0x10]), Base256::new(vec![0x20]));
+        let midpoint = a.ring_midpoint(&b, 1);
+        assert_eq!(Base256::weighted_point(&a, &b, u64::MAX, u64::MAX, 1), midpoint);
+    }
+
+    #[test]
+    #[should_panic(expected = "Weights must not both be zero")]
+    fn test_weighted_point_zero_weights() {
+        Base256::weighted_point(&Base256::new(vec![1]), &Base256::new(vec![2]), 0, 0, 1);
+    }
+
+    #[test]
+    fn test_ring_distance_min_ties_go_clockwise() {
+        let zero = Base256::new(vec![0]);
+        let half = Base256::new(vec![0x80]);
+        assert_eq!(zero.ring_distance_min(&half, 1), (half.clone(), RingDirection::Clockwise));
+        assert_eq!(half.ring_distance_min(&zero, 1), (half.clone(), RingDirection::Clockwise));
+        assert_eq!(zero.ring_distance_min(&zero, 1), (zero, RingDirection::Clockwise));
+    }
+
+    #[test]
+    fn test_ring_distance_min_matches_u16() {
+        for (a, b) in [(0u16, 1u16), (1, 0), (0xfff0, 0x0010), (0x1234, 0xabcd)] {
+            let (distance, direction) = Base256::from(a).ring_distance_min(&Base256::from(b), 2);
+            let expected = b.wrapping_sub(a).min(a.wrapping_sub(b));
+            assert_eq!(u64::try_from(&distance), Ok(expected as u64));
+            let clockwise = b.wrapping_sub(a) <= a.wrapping_sub(b);
+            assert_eq!(direction == RingDirection::Clockwise, clockwise);
+        }
+    }
+}
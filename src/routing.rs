@@ -0,0 +1,206 @@
+//! Kademlia routing table, built on [`Base256::bucket_index`] and [`Base256::k_closest`].
+//! ```
+//! use byte_arithmetic::routing::KBuckets;
+//! use byte_arithmetic::Base256;
+//! let mut table = KBuckets::new(Base256::new(vec![0b1000_0000]), 2);
+//! for id in [0b1000_0001, 0b0000_0001, 0b0000_0010, 0b0000_0011] {
+//!     table.insert(Base256::new(vec![id]), id);
+//! }
+//! // The far bucket holds two peers, so the least recently seen was evicted.
+//! assert_eq!(table.get(&Base256::new(vec![0b0000_0001])), None);
+//! let closest = table.closest(&Base256::new(vec![0]), 2);
+//! assert_eq!(closest.iter().map(|x| *x.1).collect::<Vec<u8>>(), [0b0000_0010, 0b0000_0011]);
+//! ```
+
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::vec::Vec;
+use crate::Base256;
+use core::borrow::Borrow;
+
+/// Peers at XOR distance `2^i..2^(i + 1)` from the local ID, least recently seen first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bucket<V> {
+    entries: VecDeque<(Base256, V)>,
+}
+
+impl<V> Bucket<V> {
+    fn new() -> Self {
+        Bucket {
+            entries: VecDeque::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The peer next in line for eviction, i.e. the one to ping before replacing it.
+    pub fn least_recent(&self) -> Option<(&Base256, &V)> {
+        self.entries.front().map(|(id, value)| (id, value))
+    }
+
+    /// Peers from least to most recently seen.
+    pub fn iter(&self) -> impl Iterator<Item = (&Base256, &V)> {
+        self.entries.iter().map(|(id, value)| (id, value))
+    }
+
+    fn position(&self, id: &Base256) -> Option<usize> {
+        self.entries.iter().position(|(x, _)| x == id)
+    }
+}
+
+/// Routing table of up to `k` peers per bucket, keyed by XOR distance from `local_id`.
+///
+/// Seeing a peer again moves it to the back of its bucket. Inserting into a full bucket evicts
+/// its least recently seen peer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KBuckets<V> {
+    local_id: Base256,
+    k: usize,
+    buckets: BTreeMap<usize, Bucket<V>>,
+}
+
+impl<V> KBuckets<V> {
+    /// Panics if `k` is zero.
+    pub fn new(local_id: Base256, k: usize) -> Self {
+        if k == 0 {
+            panic!("Buckets must hold at least one peer")
+        }
+        KBuckets {
+            local_id,
+            k,
+            buckets: BTreeMap::new(),
+        }
+    }
+
+    pub fn local_id(&self) -> &Base256 {
+        &self.local_id
+    }
+
+    /// Number of peers across every bucket.
+    pub fn len(&self) -> usize {
+        self.buckets.values().map(Bucket::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buckets.is_empty()
+    }
+
+    /// Record `id` as just seen with `value`, returning the peer evicted to make room, if any.
+    ///
+    /// A peer already present has its value replaced and nothing is evicted. The local ID is
+    /// never inserted.
+    pub fn insert(&mut self, id: Base256, value: V) -> Option<(Base256, V)> {
+        let index = self.local_id.bucket_index(&id)?;
+        let bucket = self.buckets.entry(index).or_insert_with(Bucket::new);
+        let mut evicted = None;
+        if let Some(position) = bucket.position(&id) {
+            bucket.entries.remove(position);
+        } else if bucket.len() == self.k {
+            evicted = bucket.entries.pop_front();
+        }
+        bucket.entries.push_back((id, value));
+        evicted
+    }
+
+    pub fn remove(&mut self, id: &Base256) -> Option<V> {
+        let index = self.local_id.bucket_index(id)?;
+        let bucket = self.buckets.get_mut(&index)?;
+        let (_, value) = bucket.entries.remove(bucket.position(id)?)?;
+        if bucket.is_empty() {
+            self.buckets.remove(&index);
+        }
+        Some(value)
+    }
+
+    pub fn get(&self, id: &Base256) -> Option<&V> {
+        let bucket = self.buckets.get(&self.local_id.bucket_index(id)?)?;
+        bucket.position(id).map(|x| &bucket.entries[x].1)
+    }
+
+    /// The `k` known peers closest to `target` by XOR distance, closest first.
+    pub fn closest(&self, target: &Base256, k: usize) -> Vec<(&Base256, &V)> {
+        let peers = self
+            .buckets
+            .values()
+            .flat_map(Bucket::iter)
+            .map(|(id, value)| Peer { id, value });
+        Base256::k_closest(target, peers, k)
+            .into_iter()
+            .map(|x| (x.id, x.value))
+            .collect()
+    }
+
+    /// Non-empty buckets with their index, nearest first.
+    pub fn buckets(&self) -> impl Iterator<Item = (usize, &Bucket<V>)> {
+        self.buckets.iter().map(|(index, bucket)| (*index, bucket))
+    }
+
+    pub fn bucket(&self, index: usize) -> Option<&Bucket<V>> {
+        self.buckets.get(&index)
+    }
+}
+
+/// A peer borrowed from a bucket, compared by its ID in `k_closest`.
+struct Peer<'a, V> {
+    id: &'a Base256,
+    value: &'a V,
+}
+
+impl<V> Borrow<Base256> for Peer<'_, V> {
+    fn borrow(&self) -> &Base256 {
+        self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn id(x: u16) -> Base256 {
+        Base256::new(x.to_be_bytes().to_vec())
+    }
+
+    #[test]
+    fn test_peers_land_in_bucket_index() {
+        let mut table = KBuckets::new(id(0), 20);
+        let evicted = (1..1024u16).filter_map(|x| table.insert(id(x), x)).count();
+        assert_eq!(table.len() + evicted, 1023);
+        let sizes: Vec<(usize, usize)> = table.buckets().map(|(i, x)| (i, x.len())).collect();
+        assert_eq!(sizes, (0..10).map(|i| (i, (1usize << i).min(20))).collect::<Vec<_>>());
+        assert_eq!(table.insert(id(0), 0), None);
+        assert_eq!(table.get(&id(0)), None);
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let mut table = KBuckets::new(id(0), 2);
+        table.insert(id(0x80), 1);
+        table.insert(id(0x81), 2);
+        // Seeing 0x80 again makes 0x81 the least recent.
+        assert_eq!(table.insert(id(0x80), 3), None);
+        assert_eq!(table.bucket(7).unwrap().least_recent(), Some((&id(0x81), &2)));
+        assert_eq!(table.insert(id(0x82), 4), Some((id(0x81), 2)));
+        let bucket: Vec<i32> = table.bucket(7).unwrap().iter().map(|x| *x.1).collect();
+        assert_eq!(bucket, vec![3, 4]);
+    }
+
+    #[test]
+    fn test_closest_and_remove() {
+        let mut table = KBuckets::new(id(0x1000), 4);
+        for x in [0x1001u16, 0x1010, 0x2000, 0x0fff, 0x8000] {
+            table.insert(id(x), x);
+        }
+        let closest: Vec<u16> = table.closest(&id(0x0ff0), 3).iter().map(|x| *x.1).collect();
+        assert_eq!(closest, vec![0x0fff, 0x1010, 0x1001]);
+        assert_eq!(table.remove(&id(0x8000)), Some(0x8000));
+        assert_eq!(table.remove(&id(0x8000)), None);
+        assert!(table.bucket(15).is_none());
+        assert_eq!(table.closest(&id(0), 10).len(), 4);
+    }
+}
@@ -0,0 +1,49 @@
+//! Padded standard Base64 string in every format.
+
+use crate::{Base256, Base64Alphabet};
+use core::fmt;
+
+pub fn serialize<S>(value: &Base256, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ::serde::Serializer,
+{
+    serializer.serialize_str(&value.to_base64(Base64Alphabet::Standard, true))
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Base256, D::Error>
+where
+    D: ::serde::Deserializer<'de>,
+{
+    deserializer.deserialize_str(Base64Visitor)
+}
+
+struct Base64Visitor;
+
+impl<'de> ::serde::de::Visitor<'de> for Base64Visitor {
+    type Value = Base256;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a base64 string")
+    }
+
+    fn visit_str<E: ::serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Base256::from_base64(v, Base64Alphabet::Standard).map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Base256;
+    use serde_test::{assert_tokens, Token};
+
+    #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    struct Wrapper(#[serde(with = "super")] Base256);
+
+    #[test]
+    fn test_base64_field() {
+        assert_tokens(
+            &Wrapper(Base256::new(vec![0xfb, 0xff])),
+            &[Token::NewtypeStruct { name: "Wrapper" }, Token::Str("+/8=")],
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use super::{BytesVisitor, HexVisitor};
+use crate::{Base256, OverflowError};
+
+/// Base256 holding at most `MAX` bytes, checked on construction and while deserializing.
+///
+/// Deserializing into Base256 accepts any length, so untrusted input can make it allocate as much
+/// as the input claims. A field of this type rejects longer input before allocating it, using the
+/// same representation as Base256.
+/// ```
+/// use byte_arithmetic::{Base256, BoundedBase256};
+/// let id: BoundedBase256<4> = serde_json::from_str("\"0xdeadbeef\"").unwrap();
+/// assert_eq!(*id, Base256::new(vec![0xde, 0xad, 0xbe, 0xef]));
+/// assert!(serde_json::from_str::<BoundedBase256<4>>("\"0xdeadbeef00\"").is_err());
+/// ```
+#[derive(PartialEq, Ord, PartialOrd, Eq, Clone, Debug, Hash)]
+pub struct BoundedBase256<const MAX: usize> {
+    inner: Base256,
+}
+
+impl<const MAX: usize> BoundedBase256<MAX> {
+    pub fn into_inner(self) -> Base256 {
+        self.inner
+    }
+}
+
+impl<const MAX: usize> core::ops::Deref for BoundedBase256<MAX> {
+    type Target = Base256;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<const MAX: usize> From<BoundedBase256<MAX>> for Base256 {
+    fn from(value: BoundedBase256<MAX>) -> Self {
+        value.inner
+    }
+}
+
+impl<const MAX: usize> TryFrom<Base256> for BoundedBase256<MAX> {
+    type Error = OverflowError;
+
+    /// Errors if the value has more than `MAX` bytes, counting leading zeros.
+    fn try_from(value: Base256) -> Result<Self, Self::Error> {
+        if value.len() > MAX {
+            return Err(OverflowError::new("BoundedBase256", value.len(), MAX));
+        }
+        Ok(BoundedBase256 { inner: value })
+    }
+}
+
+impl<const MAX: usize> ::serde::Serialize for BoundedBase256<MAX> {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+impl<'de, const MAX: usize> ::serde::Deserialize<'de> for BoundedBase256<MAX> {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let inner = if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HexVisitor::new(MAX))?
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor::new(MAX))?
+        };
+        Ok(BoundedBase256 { inner })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Compact, Configure, Token};
+
+    #[test]
+    fn test_bounded_tokens() {
+        let value = BoundedBase256::<2>::try_from(Base256::new(vec![1, 2])).unwrap();
+        assert_tokens(&value.compact(), &[Token::Bytes(&[1, 2])]);
+        assert_de_tokens_error::<Compact<BoundedBase256<2>>>(
+            &[Token::Bytes(&[1, 2, 3])],
+            "input longer than 2 bytes",
+        );
+    }
+
+    #[test]
+    fn test_bounded_seq_stops_early() {
+        assert_de_tokens_error::<Compact<BoundedBase256<1>>>(
+            &[
+                Token::Seq { len: Some(usize::MAX) },
+                Token::U8(1),
+                Token::U8(2),
+            ],
+            "input longer than 1 bytes",
+        );
+    }
+
+    #[test]
+    fn test_try_from() {
+        let err = BoundedBase256::<1>::try_from(Base256::new(vec![0, 1])).unwrap_err();
+        assert_eq!(err.required_bytes(), 2);
+    }
+}
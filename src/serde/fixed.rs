@@ -0,0 +1,55 @@
+use alloc::format;
+use super::{BytesVisitor, HexVisitor};
+use crate::{Base256, FixedBase256};
+
+/// Same representation as Base256, with exactly `N` bytes required on the way in.
+impl<const N: usize> ::serde::Serialize for FixedBase256<N> {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&Base256::from(*self).to_hex())
+        } else {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+}
+
+impl<'de, const N: usize> ::serde::Deserialize<'de> for FixedBase256<N> {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HexVisitor::new(N))?
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor::new(N))?
+        };
+        let bytes: [u8; N] = value.as_slice().try_into().map_err(|_| {
+            ::serde::de::Error::custom(format!("expected {} bytes, found {}", N, value.len()))
+        })?;
+        Ok(FixedBase256::from_be_bytes(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Token};
+
+    #[test]
+    fn test_fixed_tokens() {
+        let value = FixedBase256::from_be_bytes([0, 0xab]);
+        assert_tokens(&value.readable(), &[Token::Str("00ab")]);
+        assert_tokens(&value.compact(), &[Token::Bytes(&[0, 0xab])]);
+        assert_de_tokens_error::<serde_test::Compact<FixedBase256<2>>>(
+            &[Token::Bytes(&[0xab])],
+            "expected 2 bytes, found 1",
+        );
+    }
+
+    #[test]
+    fn test_postcard_is_length_prefixed_bytes() {
+        let hash = Base256::new(vec![0xee; 32]);
+        let encoded = postcard::to_allocvec(&hash).unwrap();
+        assert_eq!(encoded.len(), 33);
+        assert_eq!(postcard::from_bytes::<Base256>(&encoded).unwrap(), hash);
+        let fixed = FixedBase256::from_be_bytes([0xee; 32]);
+        assert_eq!(postcard::to_allocvec(&fixed).unwrap(), encoded);
+    }
+}
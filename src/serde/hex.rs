@@ -0,0 +1,18 @@
+//! Lowercase hex string of every byte in every format, accepting an optional `0x` prefix.
+
+use super::HexVisitor;
+use crate::Base256;
+
+pub fn serialize<S>(value: &Base256, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ::serde::Serializer,
+{
+    serializer.serialize_str(&value.to_hex())
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Base256, D::Error>
+where
+    D: ::serde::Deserializer<'de>,
+{
+    deserializer.deserialize_str(HexVisitor::new(usize::MAX))
+}
@@ -0,0 +1,205 @@
+//! Serde support. Base256 picks hex or raw bytes depending on the format; the submodules fix a
+//! representation per field through `#[serde(with = "...")]`.
+//! ```
+//! use byte_arithmetic::Base256;
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Record {
+//!     #[serde(with = "byte_arithmetic::serde::raw")]
+//!     key: Base256,
+//!     #[serde(with = "byte_arithmetic::serde::hex")]
+//!     value: Base256,
+//! }
+//! let record = Record { key: Base256::new(vec![1, 2]), value: Base256::new(vec![0xff]) };
+//! assert_eq!(
+//!     serde_json::to_string(&record).unwrap(),
+//!     r#"{"key":[1,2],"value":"ff"}"#
+//! );
+//! ```
+
+use alloc::format;
+use alloc::vec::Vec;
+use crate::hex::strip_hex_prefix;
+use crate::Base256;
+use core::fmt;
+
+#[cfg(feature = "base64")]
+pub mod base64;
+mod bounded;
+mod fixed;
+pub mod hex;
+pub mod raw;
+
+pub use bounded::BoundedBase256;
+
+/// Human-readable formats (JSON, TOML, ..) get a lowercase hex string of every byte, and accept
+/// one with or without a `0x` prefix. Binary formats get the raw bytes as a single byte buffer,
+/// so bincode or postcard write a length and the bytes rather than one element per byte.
+/// ```
+/// use byte_arithmetic::Base256;
+/// let value = Base256::new(vec![0, 0xab, 0xcd]);
+/// assert_eq!(serde_json::to_string(&value).unwrap(), "\"00abcd\"");
+/// assert_eq!(serde_json::from_str::<Base256>("\"0x00abcd\"").unwrap(), value);
+/// ```
+impl ::serde::Serialize for Base256 {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_hex())
+        } else {
+            serializer.serialize_bytes(&self.inner)
+        }
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Base256 {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HexVisitor::new(usize::MAX))
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor::new(usize::MAX))
+        }
+    }
+}
+
+/// Error for input decoding to more than `max_bytes`, raised before the bytes are allocated.
+fn too_long<E: ::serde::de::Error>(max_bytes: usize) -> E {
+    E::custom(format!("input longer than {} bytes", max_bytes))
+}
+
+/// Visitors take the largest number of bytes to accept, `usize::MAX` for no limit.
+struct HexVisitor {
+    max_bytes: usize,
+}
+
+impl HexVisitor {
+    fn new(max_bytes: usize) -> Self {
+        HexVisitor { max_bytes }
+    }
+}
+
+impl<'de> ::serde::de::Visitor<'de> for HexVisitor {
+    type Value = Base256;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a hex string")
+    }
+
+    fn visit_str<E: ::serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let digits = strip_hex_prefix(v).0;
+        if digits.is_empty() {
+            return Ok(Base256::empty());
+        }
+        if digits.len().div_ceil(2) > self.max_bytes {
+            return Err(too_long(self.max_bytes));
+        }
+        Base256::from_hex(v).map_err(E::custom)
+    }
+}
+
+struct BytesVisitor {
+    max_bytes: usize,
+}
+
+impl BytesVisitor {
+    fn new(max_bytes: usize) -> Self {
+        BytesVisitor { max_bytes }
+    }
+}
+
+impl<'de> ::serde::de::Visitor<'de> for BytesVisitor {
+    type Value = Base256;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a byte array")
+    }
+
+    fn visit_bytes<E: ::serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        if v.len() > self.max_bytes {
+            return Err(too_long(self.max_bytes));
+        }
+        Ok(Base256::from(v))
+    }
+
+    fn visit_byte_buf<E: ::serde::de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        if v.len() > self.max_bytes {
+            return Err(too_long(self.max_bytes));
+        }
+        Ok(Base256::new(v))
+    }
+
+    /// Formats without a native byte type hand bytes over as a sequence. The length hint comes
+    /// from the input, so it is not trusted for more than a small preallocation.
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: ::serde::de::SeqAccess<'de>,
+    {
+        let capacity = seq.size_hint().unwrap_or(0).min(self.max_bytes).min(4096);
+        let mut res = Vec::with_capacity(capacity);
+        while let Some(byte) = seq.next_element()? {
+            if res.len() == self.max_bytes {
+                return Err(too_long(self.max_bytes));
+            }
+            res.push(byte);
+        }
+        Ok(Base256::new(res))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_de_tokens, assert_tokens, Configure, Token};
+
+    #[test]
+    fn test_readable() {
+        let value = Base256::new(vec![0, 1, 0xff]);
+        assert_tokens(&value.clone().readable(), &[Token::Str("0001ff")]);
+        assert_de_tokens(&value.readable(), &[Token::Str("0x0001FF")]);
+        assert_tokens(&Base256::empty().readable(), &[Token::Str("")]);
+    }
+
+    #[test]
+    fn test_compact() {
+        let value = Base256::new(vec![0, 1, 0xff]);
+        assert_tokens(&value.clone().compact(), &[Token::Bytes(&[0, 1, 0xff])]);
+        assert_de_tokens(
+            &value.compact(),
+            &[
+                Token::Seq { len: Some(3) },
+                Token::U8(0),
+                Token::U8(1),
+                Token::U8(0xff),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    struct Fields {
+        #[serde(with = "hex")]
+        hex: Base256,
+        #[serde(with = "raw")]
+        raw: Base256,
+    }
+
+    #[test]
+    fn test_with_helpers_ignore_format() {
+        let fields = Fields {
+            hex: Base256::new(vec![0xab]),
+            raw: Base256::new(vec![0xcd]),
+        };
+        let tokens = [
+            Token::Struct { name: "Fields", len: 2 },
+            Token::Str("hex"),
+            Token::Str("ab"),
+            Token::Str("raw"),
+            Token::Bytes(&[0xcd]),
+            Token::StructEnd,
+        ];
+        assert_tokens(&fields.compact(), &tokens);
+    }
+
+    #[test]
+    fn test_invalid_hex() {
+        assert!(serde_json::from_str::<Base256>("\"0xzz\"").is_err());
+    }
+}
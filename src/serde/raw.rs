@@ -0,0 +1,18 @@
+//! Raw bytes in every format, which is a sequence of numbers in formats without a byte type.
+
+use super::BytesVisitor;
+use crate::Base256;
+
+pub fn serialize<S>(value: &Base256, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ::serde::Serializer,
+{
+    serializer.serialize_bytes(value)
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Base256, D::Error>
+where
+    D: ::serde::Deserializer<'de>,
+{
+    deserializer.deserialize_byte_buf(BytesVisitor::new(usize::MAX))
+}
@@ -0,0 +1,108 @@
+use alloc::vec::Vec;
+use crate::{Base256, ParseError};
+
+impl Base256 {
+    /// Unsigned LEB128 of the value: seven bits per byte, least significant group first, with the
+    /// high bit set on every byte but the last. Zero is `[0]`.
+    /// ```
+    /// use byte_arithmetic::Base256;
+    /// let value = Base256::new(vec![0x01, 0x2c]);
+    /// assert_eq!(value.to_varint(), vec![0xac, 0x02]);
+    /// assert_eq!(Base256::from_varint(&[0xac, 0x02, 0xff]), Ok((value, 2)));
+    /// ```
+    pub fn to_varint(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(self.len() * 8 / 7 + 1);
+        let mut buffer: u16 = 0;
+        let mut bits = 0;
+        for byte in self.significant_bytes().iter().rev() {
+            buffer |= (*byte as u16) << bits;
+            bits += 8;
+            while bits >= 7 {
+                res.push((buffer & 0x7f) as u8 | 0x80);
+                buffer >>= 7;
+                bits -= 7;
+            }
+        }
+        res.push(buffer as u8);
+        // Drop the groups that only hold leading zero bits.
+        while res.len() > 1 && res[res.len() - 1] == 0 {
+            res.pop();
+            let last = res.len() - 1;
+            res[last] &= 0x7f;
+        }
+        res
+    }
+
+    /// Decode an unsigned LEB128 prefix of `bytes`, returning the value in its minimal width and
+    /// the number of bytes consumed.
+    ///
+    /// Fails with [`ParseError::InvalidLength`] if the input ends before a byte without the high
+    /// bit set.
+    pub fn from_varint(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        if bytes.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let consumed = bytes
+            .iter()
+            .position(|x| x & 0x80 == 0)
+            .ok_or(ParseError::InvalidLength)?
+            + 1;
+        let mut little_endian = Vec::with_capacity(consumed * 7 / 8 + 1);
+        let mut buffer: u16 = 0;
+        let mut bits = 0;
+        for group in &bytes[..consumed] {
+            buffer |= ((group & 0x7f) as u16) << bits;
+            bits += 7;
+            if bits >= 8 {
+                little_endian.push(buffer as u8);
+                buffer >>= 8;
+                bits -= 8;
+            }
+        }
+        little_endian.push(buffer as u8);
+        while little_endian.len() > 1 && little_endian[little_endian.len() - 1] == 0 {
+            little_endian.pop();
+        }
+        little_endian.reverse();
+        Ok((Base256::new(little_endian), consumed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_varint_matches_u64() {
+        for value in [0u64, 1, 127, 128, 255, 300, 16_384, u32::MAX as u64, u64::MAX] {
+            let mut expected = vec![];
+            let mut rest = value;
+            loop {
+                let group = (rest & 0x7f) as u8;
+                rest >>= 7;
+                if rest == 0 {
+                    expected.push(group);
+                    break;
+                }
+                expected.push(group | 0x80);
+            }
+            let encoded = Base256::from(value).to_varint();
+            assert_eq!(encoded, expected, "{}", value);
+            assert_eq!(
+                Base256::from_varint(&encoded),
+                Ok((Base256::from(value), encoded.len()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_varint_wide_and_invalid() {
+        let value = Base256::new(vec![0, 0x80, 0, 0, 0, 0, 0, 0, 0, 0]);
+        let encoded = value.to_varint();
+        assert_eq!(encoded.len(), 11);
+        assert_eq!(Base256::from_varint(&encoded), Ok((value.significant_bytes().into(), 11)));
+        assert_eq!(Base256::from_varint(&[]), Err(ParseError::Empty));
+        assert_eq!(Base256::from_varint(&[0x80, 0x80]), Err(ParseError::InvalidLength));
+        assert_eq!(Base256::from_varint(&[0x80, 0x00]), Ok((Base256::new(vec![0]), 2)));
+    }
+}
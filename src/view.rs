@@ -0,0 +1,142 @@
+use crate::{
+    add_assign_bytes, add_bytes, mul_bytes, sub_assign_bytes, sub_bytes, xor_bytes, Base256,
+};
+
+/// Borrowed big-endian bytes used as an arithmetic operand, so a digest or a slice of a larger
+/// buffer can be combined with a Base256 without first being copied into one.
+///
+/// Results are owned Base256 values with the same width rules as the owned operators.
+/// ```
+/// use byte_arithmetic::{Base256, Base256Ref};
+/// let digest = [0x00u8, 0xff];
+/// let mut total = Base256::new(vec![0, 1]);
+/// total += Base256Ref(&digest);
+/// assert_eq!(total, Base256::new(vec![1, 0]));
+/// assert_eq!(Base256Ref(&digest) + &total, Base256::new(vec![1, 0xff]));
+/// assert_eq!(Base256Ref(&digest) ^ Base256Ref(&[0x0f]), Base256::new(vec![0x0f, 0xff]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Base256Ref<'a>(pub &'a [u8]);
+
+impl core::ops::Deref for Base256Ref<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl AsRef<[u8]> for Base256Ref<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl<'a> From<&'a [u8]> for Base256Ref<'a> {
+    fn from(bytes: &'a [u8]) -> Self {
+        Base256Ref(bytes)
+    }
+}
+
+impl<'a> From<&'a Base256> for Base256Ref<'a> {
+    fn from(value: &'a Base256) -> Self {
+        Base256Ref(&value.inner)
+    }
+}
+
+impl From<Base256Ref<'_>> for Base256 {
+    fn from(value: Base256Ref<'_>) -> Self {
+        Base256::from(value.0)
+    }
+}
+
+/// Implement a binary operator between a view and every other operand, in both orders, applying
+/// `$func` to the underlying bytes.
+macro_rules! impl_view_binop {
+    (impl $imp:ident, $method:ident, $func:ident) => {
+        impl core::ops::$imp<Base256Ref<'_>> for Base256Ref<'_> {
+            type Output = Base256;
+
+            fn $method(self, rhs: Base256Ref<'_>) -> Self::Output {
+                Base256::new($func(self.0, rhs.0))
+            }
+        }
+
+        impl core::ops::$imp<&Base256> for Base256Ref<'_> {
+            type Output = Base256;
+
+            fn $method(self, rhs: &Base256) -> Self::Output {
+                Base256::new($func(self.0, &rhs.inner))
+            }
+        }
+
+        impl core::ops::$imp<Base256> for Base256Ref<'_> {
+            type Output = Base256;
+
+            fn $method(self, rhs: Base256) -> Self::Output {
+                Base256::new($func(self.0, &rhs.inner))
+            }
+        }
+
+        impl core::ops::$imp<Base256Ref<'_>> for &Base256 {
+            type Output = Base256;
+
+            fn $method(self, rhs: Base256Ref<'_>) -> Self::Output {
+                Base256::new($func(&self.inner, rhs.0))
+            }
+        }
+
+        impl core::ops::$imp<Base256Ref<'_>> for Base256 {
+            type Output = Base256;
+
+            fn $method(self, rhs: Base256Ref<'_>) -> Self::Output {
+                Base256::new($func(&self.inner, rhs.0))
+            }
+        }
+    };
+}
+
+impl_view_binop!(impl BitXor, bitxor, xor_bytes);
+impl_view_binop!(impl Mul, mul, mul_bytes);
+impl_view_binop!(impl Sub, sub, sub_bytes);
+impl_view_binop!(impl Add, add, add_bytes);
+
+impl core::ops::AddAssign<Base256Ref<'_>> for Base256 {
+    fn add_assign(&mut self, rhs: Base256Ref<'_>) {
+        add_assign_bytes(self, rhs.0)
+    }
+}
+
+impl core::ops::SubAssign<Base256Ref<'_>> for Base256 {
+    fn sub_assign(&mut self, rhs: Base256Ref<'_>) {
+        sub_assign_bytes(self, rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_view_ops_match_owned() {
+        let (a, b) = (Base256::new(vec![0xff, 0x01]), Base256::new(vec![0x02, 0xff, 0xff]));
+        let (x, y) = (Base256Ref::from(&a), Base256Ref::from(&b));
+        assert_eq!(x + y, &a + &b);
+        assert_eq!(y - x, &b - &a);
+        assert_eq!(x * &b, &a * &b);
+        assert_eq!(&a ^ y, &a ^ &b);
+        assert_eq!(b.clone() - x, &b - &a);
+        let mut total = a.clone();
+        total += y;
+        total -= x;
+        assert_eq!(total, b.clone().with_width(3));
+        assert_eq!(Base256::from(y), b);
+    }
+
+    #[test]
+    #[should_panic(expected = "Underflow")]
+    fn test_view_sub_underflow() {
+        let _ = Base256Ref(&[1]) - Base256Ref(&[2]);
+    }
+}
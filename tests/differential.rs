@@ -0,0 +1,89 @@
+//! Arithmetic checked against `num_bigint::BigUint` on random operands of varied lengths.
+//!
+//! Bytes are drawn mostly from `0x00` and `0xff` so that long carry and borrow chains, which
+//! uniformly random bytes almost never produce, are exercised on every run.
+
+use byte_arithmetic::Base256;
+use num_bigint::BigUint;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+fn operand() -> impl Strategy<Value = Base256> {
+    let byte = prop_oneof![Just(0u8), Just(0xffu8), Just(1u8), any::<u8>()];
+    vec(byte, 0..40).prop_map(Base256::new)
+}
+
+/// Operands long enough to take the Karatsuba path, which starts at 32 limbs.
+fn long_operand() -> impl Strategy<Value = Base256> {
+    let byte = prop_oneof![Just(0u8), Just(0xffu8), any::<u8>()];
+    vec(byte, 256..640).prop_map(Base256::new)
+}
+
+fn big(value: &Base256) -> BigUint {
+    BigUint::from_bytes_be(value)
+}
+
+fn modulus(byte_length: usize) -> BigUint {
+    BigUint::from(1u8) << (8 * byte_length)
+}
+
+proptest! {
+    #[test]
+    fn add_matches(a in operand(), b in operand()) {
+        let sum = &a + &b;
+        prop_assert_eq!(big(&sum), big(&a) + big(&b));
+        prop_assert!(sum.len() >= a.len().max(b.len()));
+    }
+
+    #[test]
+    fn sub_matches(a in operand(), b in operand()) {
+        let (high, low) = if big(&a) >= big(&b) { (a, b) } else { (b, a) };
+        prop_assert_eq!(big(&(&high - &low)), big(&high) - big(&low));
+    }
+
+    #[test]
+    fn mul_matches(a in operand(), b in operand()) {
+        prop_assert_eq!(big(&(&a * &b)), big(&a) * big(&b));
+    }
+
+    #[test]
+    fn long_mul_matches(a in long_operand(), b in long_operand()) {
+        prop_assert_eq!(big(&(&a * &b)), big(&a) * big(&b));
+    }
+
+    #[test]
+    fn scalar_mul_matches(a in operand(), k in any::<u8>()) {
+        prop_assert_eq!(big(&(a.clone() * k)), big(&a) * k);
+        prop_assert_eq!(big(&a.clone().scalar_multiply(k)), big(&a) * k);
+    }
+
+    #[test]
+    fn wrapped_add_matches(a in operand(), b in operand(), byte_length in 0usize..48) {
+        let expected = (big(&a) + big(&b)) % modulus(byte_length);
+        let wrapped = a.wrapped_add(b, byte_length);
+        prop_assert!(wrapped.len() <= byte_length);
+        prop_assert_eq!(big(&wrapped), expected);
+    }
+
+    #[test]
+    fn wrapped_scalar_multiply_matches(
+        a in operand(),
+        k in any::<u8>(),
+        byte_length in 1usize..48,
+    ) {
+        let expected = (big(&a) * k) % modulus(byte_length);
+        prop_assert_eq!(big(&a.wrapped_scalar_multiply(k, byte_length)), expected);
+    }
+
+    /// Xor pairs bytes from the most significant end, so only equal widths agree numerically.
+    #[test]
+    fn xor_matches(a in operand(), b in operand()) {
+        let width = a.len().max(b.len());
+        let mut padded_a = vec![0; width - a.len()];
+        padded_a.extend_from_slice(&a);
+        let mut padded_b = vec![0; width - b.len()];
+        padded_b.extend_from_slice(&b);
+        let (a, b) = (Base256::new(padded_a), Base256::new(padded_b));
+        prop_assert_eq!(big(&(&a ^ &b)), big(&a) ^ big(&b));
+    }
+}